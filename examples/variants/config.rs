@@ -4,9 +4,9 @@ use ratatui::{
     text::Line,
     widgets::{Padding, Widget},
 };
-use tui_widget_list::{ListBuilder, ListView};
+use tui_widget_list::{ListBuilder, ListItemContainer, ListView};
 
-use crate::common::{item_container::ListItemContainer, Colors};
+use crate::common::Colors;
 
 #[derive(PartialEq, Eq, Default, Clone)]
 pub enum Variant {
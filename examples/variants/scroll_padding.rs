@@ -1,6 +1,6 @@
-use crate::common::{item_container::ListItemContainer, Colors};
+use crate::common::Colors;
 use ratatui::{layout::Alignment, style::Stylize, text::Line, widgets::Padding};
-use tui_widget_list::{ListBuilder, ListView};
+use tui_widget_list::{ListBuilder, ListItemContainer, ListView};
 
 pub(crate) struct ScrollPaddingListView;
 
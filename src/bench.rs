@@ -0,0 +1,113 @@
+//! Headless rendering utilities for benchmarks.
+//!
+//! The functions render a list repeatedly into an off-screen buffer, so the
+//! cost of a builder can be measured with a benchmark harness like criterion
+//! without setting up a terminal.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::{layout::Rect, text::Line};
+//! use tui_widget_list::{bench, ListBuilder, ListState, ListView};
+//!
+//! let mut state = ListState::default();
+//! let area = Rect::new(0, 0, 80, 24);
+//! bench::scroll_frames(
+//!     |_| {
+//!         let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+//!         ListView::new(builder, 50_000)
+//!     },
+//!     &mut state,
+//!     100,
+//!     area,
+//! );
+//! assert_eq!(state.selected, Some(99));
+//! ```
+use std::hint::black_box;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{ListState, ListView};
+
+/// Renders `frames` frames into an off-screen buffer of the given `area`.
+/// The view is constructed for every frame by `view_factory`, which receives
+/// the index of the frame, just like an application constructs its view on
+/// every draw call.
+///
+/// Returns the buffer of the last frame.
+pub fn render_frames<'a, T, F>(
+    mut view_factory: F,
+    state: &mut ListState,
+    frames: usize,
+    area: Rect,
+) -> Buffer
+where
+    T: Widget,
+    F: FnMut(usize) -> ListView<'a, T>,
+{
+    let mut buf = Buffer::empty(area);
+    for frame in 0..frames {
+        buf.reset();
+        view_factory(frame).render(area, &mut buf, state);
+        black_box(&buf);
+    }
+    buf
+}
+
+/// Like [`render_frames`], but selects the next item before every frame,
+/// which measures the cost of scrolling through the list.
+///
+/// Returns the buffer of the last frame.
+pub fn scroll_frames<'a, T, F>(
+    mut view_factory: F,
+    state: &mut ListState,
+    frames: usize,
+    area: Rect,
+) -> Buffer
+where
+    T: Widget,
+    F: FnMut(usize) -> ListView<'a, T>,
+{
+    let mut buf = Buffer::empty(area);
+    for frame in 0..frames {
+        buf.reset();
+        let view = view_factory(frame);
+        state.set_num_elements(view.item_count);
+        state.next();
+        view.render(area, &mut buf, state);
+        black_box(&buf);
+    }
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::text::Line;
+
+    use super::*;
+    use crate::ListBuilder;
+
+    #[test]
+    fn scroll_frames_selects_next_item() {
+        // given
+        let mut state = ListState::default();
+        let area = Rect::new(0, 0, 5, 3);
+        let view = |_| {
+            ListView::new(
+                ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1)),
+                10,
+            )
+        };
+
+        // when
+        let buf = scroll_frames(view, &mut state, 5, area);
+
+        // then
+        assert_eq!(state.selected, Some(4));
+        assert_eq!(buf, Buffer::with_lines(["2    ", "3    ", "4    "]));
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use ratatui::layout::Constraint;
+
+use crate::ListBuildContext;
+
+/// A cache for the items of a list, e.g. to avoid rebuilding expensive items
+/// on every frame, or to share built items between lists.
+///
+/// The cache is attached to a list with [`ListView::item_cache`]. While laying
+/// out the viewport, the list asks the cache for each item it needs, passing
+/// the build context and a closure that evaluates the builder. The context
+/// contains everything the builder depends on, such that it can serve as the
+/// cache key.
+///
+/// [`HashMapItemCache`] is an unbounded implementation. To keep ownership of a
+/// custom cache across frames, implement the trait for a mutable reference to it.
+///
+/// [`ListView::item_cache`]: crate::ListView::item_cache
+pub trait ItemCache<T> {
+    /// Returns the item and its size constraint for the given context. On a
+    /// cache miss, the item is built with `build`.
+    fn get_or_build(
+        &mut self,
+        context: &ListBuildContext,
+        build: &mut dyn FnMut() -> (T, Constraint),
+    ) -> (T, Constraint);
+}
+
+impl<T, C: ItemCache<T> + ?Sized> ItemCache<T> for &mut C {
+    fn get_or_build(
+        &mut self,
+        context: &ListBuildContext,
+        build: &mut dyn FnMut() -> (T, Constraint),
+    ) -> (T, Constraint) {
+        (**self).get_or_build(context, build)
+    }
+}
+
+/// An unbounded item cache that keeps every built item until it is cleared.
+///
+/// The items are cloned out of the cache, so the cache is most useful for
+/// items that are cheap to clone but expensive to build. If the data behind
+/// the items changes, the cache must be cleared with [`HashMapItemCache::clear`].
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+/// use tui_widget_list::{HashMapItemCache, ListBuilder, ListState, ListView};
+///
+/// let mut cache = HashMapItemCache::default();
+/// let mut state = ListState::default();
+/// let area = Rect::new(0, 0, 10, 5);
+/// for _ in 0..2 {
+///     let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+///     let list = ListView::new(builder, 10).item_cache(&mut cache);
+///     list.render(area, &mut Buffer::empty(area), &mut state);
+/// }
+/// assert_eq!(cache.len(), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashMapItemCache<T> {
+    items: HashMap<ListBuildContext, (T, Constraint)>,
+}
+
+impl<T> Default for HashMapItemCache<T> {
+    fn default() -> Self {
+        Self {
+            items: HashMap::new(),
+        }
+    }
+}
+
+impl<T> HashMapItemCache<T> {
+    /// Returns the number of cached items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if no item is cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Removes all cached items.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+impl<T: Clone> ItemCache<T> for HashMapItemCache<T> {
+    fn get_or_build(
+        &mut self,
+        context: &ListBuildContext,
+        build: &mut dyn FnMut() -> (T, Constraint),
+    ) -> (T, Constraint) {
+        self.items
+            .entry(context.clone())
+            .or_insert_with(build)
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use ratatui::{prelude::*, widgets::StatefulWidget};
+
+    use super::*;
+    use crate::{ListBuilder, ListState, ListView};
+
+    #[test]
+    fn builds_items_once() {
+        // given
+        let builds = Cell::new(0);
+        let mut cache = HashMapItemCache::default();
+        let mut state = ListState::default();
+        let area = Rect::new(0, 0, 5, 3);
+
+        // when
+        for _ in 0..3 {
+            let builder = ListBuilder::new(|context| {
+                builds.set(builds.get() + 1);
+                (Line::from(format!("{}", context.index)), 1)
+            });
+            let list = ListView::new(builder, 10).item_cache(&mut cache);
+            list.render(area, &mut Buffer::empty(area), &mut state);
+        }
+
+        // then
+        assert_eq!(builds.get(), cache.len());
+    }
+}
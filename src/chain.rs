@@ -0,0 +1,354 @@
+use std::ops::Range;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::Style,
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::{ListBuildContext, ListBuilder, ListView};
+
+/// Concatenates several lists into one scroll surface, e.g. for lists with
+/// "recent", "pinned" and "all" sections that are backed by separate data
+/// sources but share one selection and one scrollbar.
+///
+/// Each segment consists of a [`ListBuilder`] and an item count. The segments
+/// are stitched together into a unified index space: the items of the first
+/// segment come first, followed by the items of the second segment, and so on.
+/// The builder of a segment receives the index of the item within its segment,
+/// all other fields of the [`ListBuildContext`] refer to the chained list.
+///
+/// A segment can have a header, see [`ChainedListView::segment_header`], which
+/// is inserted as a non-selectable item in front of the items of the segment.
+/// The headers are part of the unified index space and are skipped by
+/// [`ListState::next`] and [`ListState::previous`].
+///
+/// Only the closures of the segment builders are used, and whether they are
+/// [checked](ListBuilder::checked). Options such as
+/// [`ListBuilder::size_bounds`] are configured on the chained list instead,
+/// which is converted into a regular [`ListView`] with
+/// [`ChainedListView::into_list_view`].
+///
+/// [`ListState::next`]: crate::ListState::next
+/// [`ListState::previous`]: crate::ListState::previous
+///
+/// # Example
+/// ```
+/// use ratatui::{style::{Color, Style}, text::Line};
+/// use tui_widget_list::{ChainedListView, ListBuilder};
+///
+/// let pinned = ["a", "b"];
+/// let all = ["a", "b", "c", "d"];
+///
+/// let chained = ChainedListView::new()
+///     .segment(
+///         ListBuilder::new(|context| (Line::from(pinned[context.index]), 1)),
+///         pinned.len(),
+///     )
+///     .segment_style(Style::new().bg(Color::Blue))
+///     .segment(
+///         ListBuilder::new(|context| (Line::from(all[context.index]), 1)),
+///         all.len(),
+///     )
+///     .segment_header("All", Style::new().fg(Color::Yellow));
+///
+/// // The third item of the chained list is the header of the second segment,
+/// // followed by the first item of the second segment
+/// assert!(chained.is_header(2));
+/// assert_eq!(chained.locate(3), Some((1, 0)));
+///
+/// let list = chained.into_list_view().infinite_scrolling(false);
+/// ```
+pub struct ChainedListView<'a, T> {
+    segments: Vec<Segment<'a, T>>,
+}
+
+/// A list that is part of a [`ChainedListView`].
+struct Segment<'a, T> {
+    /// Builds the items of the segment.
+    builder: ListBuilder<'a, T>,
+
+    /// The number of items in the segment.
+    item_count: usize,
+
+    /// The base style of the items of the segment.
+    style: Style,
+
+    /// The header in front of the items of the segment.
+    header: Option<Line<'a>>,
+}
+
+/// The position of an index of a [`ChainedListView`] within its segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    /// The header of the segment.
+    Header(usize),
+
+    /// The item of the segment with the index within the segment.
+    Item(usize, usize),
+}
+
+impl<T> Default for ChainedListView<'_, T> {
+    fn default() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+}
+
+impl<'a, T> ChainedListView<'a, T> {
+    /// Creates a chained list without segments.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a segment with `item_count` items that are built by `builder`.
+    #[must_use]
+    pub fn segment(mut self, mut builder: ListBuilder<'a, T>, item_count: usize) -> Self {
+        builder.item_count = item_count;
+        self.segments.push(Segment {
+            builder,
+            item_count,
+            style: Style::default(),
+            header: None,
+        });
+        self
+    }
+
+    /// Sets the base style of the items of the last segment. The style is
+    /// applied before an item is rendered, so the item may override it.
+    #[must_use]
+    pub fn segment_style<S: Into<Style>>(mut self, style: S) -> Self {
+        if let Some(segment) = self.segments.last_mut() {
+            segment.style = style.into();
+        }
+        self
+    }
+
+    /// Inserts a header with the given `title` and `style` in front of the
+    /// items of the last segment. The header is not selectable.
+    #[must_use]
+    pub fn segment_header<L, S>(mut self, title: L, style: S) -> Self
+    where
+        L: Into<Line<'a>>,
+        S: Into<Style>,
+    {
+        if let Some(segment) = self.segments.last_mut() {
+            segment.header = Some(title.into().patch_style(style));
+        }
+        self
+    }
+
+    /// Returns the total number of items of all segments, including the headers.
+    #[must_use]
+    pub fn item_count(&self) -> usize {
+        self.segments.iter().map(Segment::len).sum()
+    }
+
+    /// Returns the range of indices of the items of the segment at `segment`
+    /// in the chained list, excluding its header.
+    #[must_use]
+    pub fn segment_range(&self, segment: usize) -> Option<Range<usize>> {
+        let current = self.segments.get(segment)?;
+        let start = self.segments[..segment]
+            .iter()
+            .map(Segment::len)
+            .sum::<usize>()
+            + usize::from(current.header.is_some());
+        Some(start..start + current.item_count)
+    }
+
+    /// Maps an index of the chained list to the index of its segment and the
+    /// index of the item within that segment, e.g. to resolve the selection.
+    /// Returns `None` if the index is out of bounds or belongs to a header.
+    #[must_use]
+    pub fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        match resolve(&self.segments, index)? {
+            Slot::Item(segment, index) => Some((segment, index)),
+            Slot::Header(_) => None,
+        }
+    }
+
+    /// Returns true if the index of the chained list belongs to a header.
+    #[must_use]
+    pub fn is_header(&self, index: usize) -> bool {
+        matches!(resolve(&self.segments, index), Some(Slot::Header(_)))
+    }
+
+    /// Converts the chained list into a [`ListView`] over all segments.
+    ///
+    /// The headers are made non-selectable with [`ListView::selection_filter`],
+    /// so setting another filter on the returned list makes them selectable.
+    #[must_use]
+    pub fn into_list_view(self) -> ListView<'a, ChainedItem<'a, T>>
+    where
+        T: 'a,
+    {
+        let item_count = self.item_count();
+        let headers: Vec<usize> = (0..item_count).filter(|&i| self.is_header(i)).collect();
+        let segments = self.segments;
+        let builder = ListBuilder::with_constraints(move |context| {
+            let slot = resolve(&segments, context.index).expect("the index lies within the list");
+            match slot {
+                Slot::Header(segment) => {
+                    let header = segments[segment].header.clone().unwrap_or_default();
+                    (ChainedItem::Header(header), Constraint::Length(1))
+                }
+                Slot::Item(segment, index) => {
+                    let segment = &segments[segment];
+                    let context = ListBuildContext {
+                        index,
+                        ..context.clone()
+                    };
+                    let (widget, constraint) = segment.builder.build(&context);
+                    let item = ChainedItem::Item {
+                        widget,
+                        style: segment.style,
+                    };
+                    (item, constraint)
+                }
+            }
+        });
+        ListView::new(builder, item_count).selection_filter(move |_, to| {
+            to.is_none_or(|index| headers.binary_search(&index).is_err())
+        })
+    }
+}
+
+impl<T> Segment<'_, T> {
+    /// Returns the number of items of the segment, including the header.
+    fn len(&self) -> usize {
+        self.item_count + usize::from(self.header.is_some())
+    }
+}
+
+impl<'a, T: 'a> From<ChainedListView<'a, T>> for ListView<'a, ChainedItem<'a, T>> {
+    fn from(chained: ChainedListView<'a, T>) -> Self {
+        chained.into_list_view()
+    }
+}
+
+/// Maps `index` to the header or the item of the segment that contains it.
+fn resolve<T>(segments: &[Segment<'_, T>], mut index: usize) -> Option<Slot> {
+    for (i, segment) in segments.iter().enumerate() {
+        if segment.header.is_some() {
+            if index == 0 {
+                return Some(Slot::Header(i));
+            }
+            index -= 1;
+        }
+        if index < segment.item_count {
+            return Some(Slot::Item(i, index));
+        }
+        index -= segment.item_count;
+    }
+    None
+}
+
+/// An item of a [`ChainedListView`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainedItem<'a, T> {
+    /// The header of a segment.
+    Header(Line<'a>),
+
+    /// An item of a segment, rendered with the base style of its segment.
+    Item {
+        /// The widget built by the builder of the segment.
+        widget: T,
+
+        /// The base style of the segment.
+        style: Style,
+    },
+}
+
+impl<T: Widget> Widget for ChainedItem<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self {
+            ChainedItem::Header(header) => header.render(area, buf),
+            ChainedItem::Item { widget, style } => {
+                buf.set_style(area, style);
+                widget.render(area, buf);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{style::Color, text::Line, widgets::StatefulWidget};
+
+    use super::*;
+    use crate::ListState;
+
+    #[test]
+    fn unified_index_space() {
+        // given
+        let area = Rect::new(0, 0, 2, 5);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.select(Some(3));
+        let chained = ChainedListView::new()
+            .segment(
+                ListBuilder::new(|c| (Line::from(format!("a{}", c.index)), 1)),
+                2,
+            )
+            .segment(
+                ListBuilder::new(|c| (Line::from(format!("b{}", c.index)), 1)),
+                0,
+            )
+            .segment(
+                ListBuilder::new(|c| (Line::from(format!("c{}", c.index)), 1)),
+                3,
+            )
+            .segment_style(Style::new().bg(Color::Red));
+
+        // when
+        assert_eq!(chained.segment_range(2), Some(2..5));
+        assert_eq!(chained.locate(3), Some((2, 1)));
+        assert_eq!(chained.locate(5), None);
+        chained.into_list_view().render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(["a0", "a1", "c0", "c1", "c2"]);
+        expected.set_style(Rect::new(0, 2, 2, 3), Style::new().bg(Color::Red));
+        assert_eq!(buf, expected);
+    }
+    #[test]
+    fn headers_are_skipped() {
+        // given
+        let area = Rect::new(0, 0, 2, 5);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let chained = || {
+            ChainedListView::new()
+                .segment(
+                    ListBuilder::new(|c| (Line::from(format!("a{}", c.index)), 1)),
+                    1,
+                )
+                .segment(
+                    ListBuilder::new(|c| (Line::from(format!("b{}", c.index)), 1)),
+                    2,
+                )
+                .segment_header("B", Style::new())
+        };
+        state.select(Some(0));
+        chained()
+            .into_list_view()
+            .render(area, &mut buf, &mut state);
+
+        // when
+        state.next();
+        chained()
+            .into_list_view()
+            .render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(chained().segment_range(1), Some(2..4));
+        assert_eq!(chained().locate(1), None);
+        assert_eq!(state.selected, Some(2));
+        assert_eq!(buf, Buffer::with_lines(["a0", "B ", "b0", "b1", "  "]));
+    }
+}
@@ -0,0 +1,167 @@
+//! A ready-made showcase that exercises the features of the list.
+//!
+//! The showcase renders deterministically, which makes it suitable for
+//! snapshot tests of custom themes or for terminal recordings.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+//!
+//! let area = Rect::new(0, 0, 60, 20);
+//! let mut buf = Buffer::empty(area);
+//! tui_widget_list::demo::showcase().selected(3).render(area, &mut buf);
+//! ```
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{ListBuilder, ListState, ListView, MainAxisAlignment, ScrollAxis, TruncationPolicy};
+
+/// The number of items of each list in the showcase.
+const ITEM_COUNT: usize = 20;
+
+/// Returns a widget that renders several lists side by side, covering both
+/// scroll axes, scroll padding, variable item sizes, truncation, gutters and
+/// headers.
+#[must_use]
+pub fn showcase() -> Showcase {
+    Showcase::default()
+}
+
+/// A widget that renders several lists side by side. See [`showcase`].
+#[derive(Debug, Clone, Default)]
+pub struct Showcase {
+    selected: Option<usize>,
+    style: Style,
+    selected_style: Option<Style>,
+}
+
+impl Showcase {
+    /// Selects the item at `index` in all lists.
+    #[must_use]
+    pub fn selected(mut self, index: usize) -> Self {
+        self.selected = Some(index);
+        self
+    }
+
+    /// Sets the base style of the lists.
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected items. Defaults to a blue background.
+    #[must_use]
+    pub fn selected_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selected_style = Some(style.into());
+        self
+    }
+
+    fn state(&self) -> ListState {
+        let mut state = ListState::default();
+        state.select(self.selected);
+        state
+    }
+}
+
+impl Widget for Showcase {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let selected_style = self
+            .selected_style
+            .unwrap_or_else(|| Style::default().bg(Color::Blue));
+        let [top, bottom] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(5)]).areas(area);
+        let [left, middle, right] = Layout::horizontal([Constraint::Fill(1); 3]).areas(top);
+
+        // Fixed item sizes with scroll padding
+        let builder = ListBuilder::new(move |context| {
+            let mut line = Line::from(format!("Item {}", context.index));
+            if context.is_selected {
+                line = line.style(selected_style);
+            }
+            (line, 1)
+        });
+        ListView::new(builder, ITEM_COUNT)
+            .scroll_padding(2)
+            .style(self.style)
+            .block(Block::bordered().title("Padding"))
+            .render(left, buf, &mut self.state());
+
+        // Variable item sizes with a gutter, hiding partially visible items
+        let builder = ListBuilder::new(move |context| {
+            let main_axis_size = 1 + (context.index % 3) as u16;
+            let mut item = Paragraph::new(format!("Item {}", context.index))
+                .block(Block::default().borders(Borders::LEFT));
+            if context.is_selected {
+                item = item.style(selected_style);
+            }
+            (item, main_axis_size)
+        });
+        ListView::new(builder, ITEM_COUNT)
+            .gutter(3, |index, _| Line::from(format!("{index:>2}")).dark_gray())
+            .truncation_policy(TruncationPolicy::Hide)
+            .style(self.style)
+            .block(Block::bordered().title("Sizes"))
+            .render(middle, buf, &mut self.state());
+
+        // A short, centered list with a header
+        let builder = ListBuilder::new(move |context| {
+            let mut line = Line::from(format!("Option {}", context.index)).centered();
+            if context.is_selected {
+                line = line.style(selected_style);
+            }
+            (line, 1)
+        });
+        ListView::new(builder, 3)
+            .header(Line::from("Menu").bold().centered(), 1)
+            .main_axis_alignment(MainAxisAlignment::Center)
+            .style(self.style)
+            .block(Block::bordered().title("Aligned"))
+            .render(right, buf, &mut self.state());
+
+        // Horizontal scrolling with truncated items
+        let builder = ListBuilder::new(move |context| {
+            let mut item = Paragraph::new(format!("{}", context.index))
+                .centered()
+                .block(Block::bordered().border_type(BorderType::Rounded));
+            if context.is_selected {
+                item = item.style(selected_style);
+            }
+            (item, 7)
+        });
+        ListView::new(builder, ITEM_COUNT)
+            .scroll_axis(ScrollAxis::Horizontal)
+            .style(self.style)
+            .block(Block::default().borders(Borders::TOP).title("Horizontal"))
+            .render(bottom, buf, &mut self.state());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn showcase_is_deterministic() {
+        // given
+        let area = Rect::new(0, 0, 60, 20);
+        let render = || {
+            let mut buf = Buffer::empty(area);
+            showcase().selected(5).render(area, &mut buf);
+            buf
+        };
+
+        // when
+        let (first, second) = (render(), render());
+
+        // then
+        assert_eq!(first, second);
+        assert_eq!(first.cell((1, 1)).map(|c| c.symbol()), Some("I"));
+    }
+}
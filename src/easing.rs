@@ -0,0 +1,109 @@
+/// Easing curves for animations, e.g. [`ListView::expansion_easing`].
+///
+/// The curves are evaluated with integer fixed-point arithmetic, such that
+/// animations are deterministic across platforms and do not require floats.
+///
+/// [`ListView::expansion_easing`]: crate::ListView::expansion_easing
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// Progresses at a constant rate. This is the default.
+    #[default]
+    Linear,
+
+    /// Starts slowly and accelerates (quadratic).
+    EaseIn,
+
+    /// Starts quickly and decelerates (quadratic).
+    EaseOut,
+
+    /// Accelerates during the first half and decelerates during the second half.
+    EaseInOut,
+}
+
+impl Easing {
+    /// The fixed-point representation of a progress of one.
+    pub const ONE: u32 = 1 << 16;
+
+    /// Evaluates the curve at the given fixed-point progress, where
+    /// [`Easing::ONE`] denotes the end of the animation. The progress is
+    /// clamped to `Easing::ONE`.
+    #[must_use]
+    pub fn ease(self, progress: u32) -> u32 {
+        let one = u64::from(Self::ONE);
+        let t = u64::from(progress.min(Self::ONE));
+        let eased = match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t / one,
+            Self::EaseOut => one - (one - t) * (one - t) / one,
+            Self::EaseInOut if t < one / 2 => 2 * t * t / one,
+            Self::EaseInOut => one - 2 * (one - t) * (one - t) / one,
+        };
+        // The eased value never exceeds `Easing::ONE`.
+        u32::try_from(eased).unwrap_or(Self::ONE)
+    }
+
+    /// Interpolates between `from` and `to` after `step` of `steps` steps of an
+    /// animation. Returns `to` if the animation has no steps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_widget_list::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.interpolate(0, 10, 1, 4), 2);
+    /// assert_eq!(Easing::EaseOut.interpolate(0, 10, 1, 4), 4);
+    /// assert_eq!(Easing::EaseOut.interpolate(0, 10, 4, 4), 10);
+    /// ```
+    #[must_use]
+    pub fn interpolate(self, from: u16, to: u16, step: u16, steps: u16) -> u16 {
+        if steps == 0 {
+            return to;
+        }
+        let progress = u32::from(step.min(steps)) * Self::ONE / u32::from(steps);
+        let eased = i64::from(self.ease(progress));
+        let delta = i64::from(to) - i64::from(from);
+        let value = i64::from(from) + delta * eased / i64::from(Self::ONE);
+        // The value lies between `from` and `to`.
+        u16::try_from(value).unwrap_or(to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES: [Easing; 4] = [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+    ];
+
+    #[test]
+    fn endpoints() {
+        for easing in CURVES {
+            assert_eq!(easing.ease(0), 0, "{easing:?}");
+            assert_eq!(easing.ease(Easing::ONE), Easing::ONE, "{easing:?}");
+            assert_eq!(easing.interpolate(10, 2, 0, 5), 10, "{easing:?}");
+            assert_eq!(easing.interpolate(10, 2, 5, 5), 2, "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn monotonic() {
+        for easing in CURVES {
+            let values: Vec<u16> = (0..=20)
+                .map(|step| easing.interpolate(0, 100, step, 20))
+                .collect();
+            assert!(values.windows(2).all(|w| w[0] <= w[1]), "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(Easing::Linear.interpolate(0, 100, 1, 2), 50);
+        assert_eq!(Easing::EaseIn.interpolate(0, 100, 1, 2), 25);
+        assert_eq!(Easing::EaseOut.interpolate(0, 100, 1, 2), 75);
+        assert_eq!(Easing::EaseInOut.interpolate(0, 100, 1, 2), 50);
+    }
+}
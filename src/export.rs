@@ -0,0 +1,282 @@
+use std::{
+    convert::Infallible,
+    ops::{Bound, RangeBounds},
+};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect, Size},
+    text::{Line, Span, Text},
+    widgets::Widget,
+};
+
+use crate::{ListView, ScrollAxis};
+
+impl<T: Widget> ListView<'_, T> {
+    /// Renders the items in `range` into an off-screen buffer and returns their
+    /// content as lines, e.g. to copy the list to the clipboard or to write it
+    /// to a file.
+    ///
+    /// The items are laid out one after another along the main axis inside a
+    /// viewport of the given `size`: The cross axis size of the viewport is passed
+    /// to the builder and the main axis size is used to resolve size constraints.
+    /// The returned lines keep the styles of the rendered cells, use
+    /// [`ToString::to_string`] to obtain the plain text.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{layout::Size, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("Item {}", context.index)), 1));
+    /// let list = ListView::new(builder, 3);
+    ///
+    /// let lines = list.render_to_lines(.., Size::new(6, 10));
+    /// assert_eq!(lines[2].to_string(), "Item 2");
+    /// ```
+    pub fn render_to_lines<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+        size: Size,
+    ) -> Vec<Line<'static>> {
+        let mut lines: Vec<Line<'static>> = Vec::new();
+        let result = self.render_into_lines(range, size, |_, item_lines| {
+            match self.scroll_axis {
+                ScrollAxis::Vertical => lines.extend_from_slice(item_lines),
+                ScrollAxis::Horizontal if lines.is_empty() => lines = item_lines.to_vec(),
+                ScrollAxis::Horizontal => {
+                    for (line, item_line) in lines.iter_mut().zip(item_lines) {
+                        line.spans.extend_from_slice(&item_line.spans);
+                    }
+                }
+            }
+            Ok::<(), Infallible>(())
+        });
+        let Ok(()) = result;
+
+        lines
+    }
+
+    /// Renders the items in `range` one after another and passes the lines of
+    /// each item to `sink`, together with the index of the item. Unlike
+    /// [`ListView::render_to_lines`], the lines of the whole range are never
+    /// held in memory at once, e.g. to stream a very long list to a pager or
+    /// a file. See [`ListView::render_to_lines`] for the meaning of `size`.
+    ///
+    /// For horizontal lists, the lines of an item are the rows of its column.
+    ///
+    /// Stops at the first error returned by `sink` and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of `sink`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use ratatui::{layout::Size, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("Item {}", context.index)), 1));
+    /// let list = ListView::new(builder, 10_000);
+    ///
+    /// let mut out = Vec::new();
+    /// list.render_into_lines(.., Size::new(10, 10), |_, lines| {
+    ///     lines.iter().try_for_each(|line| writeln!(out, "{}", line.to_string().trim_end()))
+    /// })?;
+    /// assert!(out.starts_with(b"Item 0\nItem 1\n"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn render_into_lines<R, E, F>(&self, range: R, size: Size, mut sink: F) -> Result<(), E>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(usize, &[Line<'static>]) -> Result<(), E>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.item_count,
+        }
+        .min(self.item_count);
+
+        let area = Rect::new(0, 0, size.width, size.height);
+        let main_axis_size = self.scroll_axis.main_size(area);
+        let cross_axis_size = self.scroll_axis.cross_size(area);
+
+        for index in start..end {
+            let buf = self.render_item_to_buffer(index, main_axis_size, cross_axis_size, false);
+            sink(index, &buffer_to_lines(&buf))?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the item at `index` off-screen and returns its content as plain
+    /// text, e.g. to implement "yank selected row" without knowing how the item
+    /// widget draws itself.
+    ///
+    /// Styles are dropped and trailing whitespace is trimmed from every line, so
+    /// the text can be copied to the clipboard directly. See
+    /// [`ListView::render_to_lines`] for the meaning of `size`.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{layout::Size, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("Item {}", context.index)), 1));
+    /// let list = ListView::new(builder, 3);
+    ///
+    /// let text = list.render_item_to_text(1, Size::new(20, 10));
+    /// assert_eq!(text.to_string(), "Item 1");
+    /// ```
+    #[must_use]
+    pub fn render_item_to_text(&self, index: usize, size: Size) -> Text<'static> {
+        let lines = self
+            .render_to_lines(index..=index, size)
+            .iter()
+            .map(|line| Line::raw(line.to_string().trim_end().to_string()))
+            .collect::<Vec<_>>();
+        Text::from(lines)
+    }
+
+    /// Builds the item at `index` and renders it in full into a buffer
+    /// that is sized to the item.
+    pub(crate) fn render_item_to_buffer(
+        &self,
+        index: usize,
+        main_axis_size: u16,
+        cross_axis_size: u16,
+        is_selected: bool,
+    ) -> Buffer {
+        let context = self
+            .builder
+            .context(index, is_selected, self.scroll_axis, cross_axis_size);
+        let (widget, item_main_axis_size) = self.builder.call_closure(&context, main_axis_size);
+
+        let area = self
+            .scroll_axis
+            .to_rect(0, 0, item_main_axis_size, cross_axis_size);
+        let mut buf = Buffer::empty(area);
+        buf.set_style(buf.area, self.style);
+        widget.render(buf.area, &mut buf);
+
+        buf
+    }
+}
+
+/// Converts the rows of a buffer into lines. Consecutive cells with the same
+/// style are merged into a single span.
+pub(crate) fn buffer_to_lines(buf: &Buffer) -> Vec<Line<'static>> {
+    let area = buf.area;
+    let mut lines = Vec::with_capacity(usize::from(area.height));
+    for y in area.top()..area.bottom() {
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            // Skip the cells that are covered by a preceding wide character.
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let Some(cell) = buf.cell(Position::new(x, y)) else {
+                continue;
+            };
+            skip = Span::raw(cell.symbol()).width().saturating_sub(1);
+
+            let style = cell.style();
+            match spans.last_mut() {
+                Some(span) if span.style == style => span.content.to_mut().push_str(cell.symbol()),
+                _ => spans.push(Span::styled(cell.symbol().to_string(), style)),
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Style};
+
+    use super::*;
+    use crate::ListBuilder;
+
+    fn list(scroll_axis: ScrollAxis) -> ListView<'static, Line<'static>> {
+        let builder = ListBuilder::new(|context| {
+            let line = Line::from(format!("{}", context.index));
+            (line, context.index as u16 + 1)
+        });
+        ListView::new(builder, 3).scroll_axis(scroll_axis)
+    }
+
+    #[test]
+    fn render_to_lines_vertical() {
+        // when
+        let lines = list(ScrollAxis::Vertical).render_to_lines(.., Size::new(2, 10));
+
+        // then
+        let text: Vec<String> = lines.iter().map(Line::to_string).collect();
+        assert_eq!(text, vec!["0 ", "1 ", "  ", "2 ", "  ", "  "]);
+    }
+
+    #[test]
+    fn render_to_lines_horizontal_range() {
+        // when
+        let lines = list(ScrollAxis::Horizontal).render_to_lines(1..=2, Size::new(10, 2));
+
+        // then
+        let text: Vec<String> = lines.iter().map(Line::to_string).collect();
+        assert_eq!(text, vec!["1 2  ", "     "]);
+    }
+
+    #[test]
+    fn render_into_lines_stops_on_error() {
+        // given
+        let mut chunks = Vec::new();
+
+        // when
+        let result =
+            list(ScrollAxis::Vertical).render_into_lines(.., Size::new(2, 10), |i, lines| {
+                if i == 2 {
+                    return Err(i);
+                }
+                chunks.push(lines.iter().map(Line::to_string).collect::<Vec<_>>());
+                Ok(())
+            });
+
+        // then
+        assert_eq!(result, Err(2));
+        assert_eq!(chunks, vec![vec!["0 "], vec!["1 ", "  "]]);
+    }
+
+    #[test]
+    fn render_item_to_text() {
+        // when
+        let text = list(ScrollAxis::Vertical).render_item_to_text(1, Size::new(3, 10));
+
+        // then
+        assert_eq!(text, Text::from(vec![Line::raw("1"), Line::raw("")]));
+    }
+
+    #[test]
+    fn buffer_to_lines_merges_styles() {
+        // given
+        let mut buf = Buffer::with_lines(vec!["abc"]);
+        buf.set_style(Rect::new(2, 0, 1, 1), Style::default().fg(Color::Red));
+
+        // when
+        let lines = buffer_to_lines(&buf);
+
+        // then
+        assert_eq!(lines[0].spans.len(), 2);
+        assert_eq!(lines[0].spans[0].content, "ab");
+        assert_eq!(lines[0].spans[1].content, "c");
+    }
+}
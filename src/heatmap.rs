@@ -0,0 +1,133 @@
+use ratatui::style::Color;
+
+use crate::view::blend_color;
+
+/// The resolution of the interpolation between the colors of a [`ColorScale`].
+const STEPS: u16 = 1000;
+
+/// A linear color scale from a low to a high color, see [`ListView::heatmap`].
+///
+/// Rgb colors are interpolated. Other colors can not be interpolated, so the
+/// scale switches from the low to the high color at the midpoint.
+///
+/// [`ListView::heatmap`]: crate::ListView::heatmap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColorScale {
+    /// The color of the value `0.0`.
+    pub low: Color,
+
+    /// The color of the value `1.0`.
+    pub high: Color,
+}
+
+impl ColorScale {
+    /// Creates a color scale from `low` at `0.0` to `high` at `1.0`.
+    #[must_use]
+    pub const fn new(low: Color, high: Color) -> Self {
+        Self { low, high }
+    }
+
+    /// Returns the color of the normalized `value`. The value is clamped to
+    /// the range from `0.0` to `1.0`, `NaN` is treated as `0.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_widget_list::ColorScale;
+    ///
+    /// let scale = ColorScale::new(Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0));
+    /// assert_eq!(scale.color_at(0.5), Color::Rgb(100, 50, 0));
+    /// assert_eq!(scale.color_at(2.0), Color::Rgb(200, 100, 0));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn color_at(&self, value: f32) -> Color {
+        let value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+        // The value is clamped, so the weight lies between 0 and `STEPS`.
+        let weight = (value * f32::from(STEPS)).round() as u16;
+        blend_color(self.low, self.high, weight, STEPS).unwrap_or(if weight * 2 < STEPS {
+            self.low
+        } else {
+            self.high
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        buffer::Buffer, layout::Rect, style::Style, text::Line, widgets::StatefulWidget,
+    };
+
+    use super::*;
+    use crate::{ListBuilder, ListState, ListView};
+
+    #[test]
+    fn non_rgb_colors_switch_at_midpoint() {
+        let scale = ColorScale::new(Color::Green, Color::Red);
+        assert_eq!(scale.color_at(0.4), Color::Green);
+        assert_eq!(scale.color_at(0.5), Color::Red);
+        assert_eq!(scale.color_at(f32::NAN), Color::Green);
+    }
+
+    #[test]
+    fn heatmap() {
+        // given
+        let area = Rect::new(0, 0, 2, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let scale = ColorScale::new(Color::Rgb(0, 0, 0), Color::Rgb(100, 0, 0));
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+        #[allow(clippy::cast_precision_loss)]
+        let list = ListView::new(builder, 3).heatmap(|index| index as f32 / 2.0, scale);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(["0 ", "1 ", "2 "]);
+        for (y, red) in [(0, 0), (1, 50), (2, 100)] {
+            expected.set_style(
+                Rect::new(0, y, 2, 1),
+                Style::new().bg(Color::Rgb(red, 0, 0)),
+            );
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn heatmap_under_selection() {
+        // given
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let scale = ColorScale::new(Color::Rgb(0, 0, 0), Color::Rgb(100, 0, 0));
+        let builder = ListBuilder::new(|c| {
+            let line = Line::from(format!("{}", c.index));
+            let style = if c.is_selected {
+                Style::new().bg(Color::Blue)
+            } else {
+                Style::new()
+            };
+            (line.style(style), 1)
+        });
+        let list = ListView::new(builder, 2).heatmap(|_| 1.0, scale);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(["0 ", "1 "]);
+        expected.set_style(
+            Rect::new(0, 0, 2, 1),
+            Style::new().bg(Color::Rgb(100, 0, 0)),
+        );
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().bg(Color::Blue));
+        assert_eq!(buf, expected);
+    }
+}
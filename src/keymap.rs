@@ -0,0 +1,537 @@
+use std::borrow::Cow;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::ListState;
+
+/// A key of a [`KeyChord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A character key. Shifted characters are given by their uppercase
+    /// letter, e.g. `G`.
+    Char(char),
+
+    /// The up arrow key.
+    Up,
+
+    /// The down arrow key.
+    Down,
+
+    /// The left arrow key.
+    Left,
+
+    /// The right arrow key.
+    Right,
+
+    /// The home key.
+    Home,
+
+    /// The end key.
+    End,
+
+    /// The page up key.
+    PageUp,
+
+    /// The page down key.
+    PageDown,
+}
+
+/// A key press, i.e. a [`Key`] with modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    /// The pressed key.
+    pub key: Key,
+
+    /// Whether the control key is held.
+    pub ctrl: bool,
+
+    /// Whether the alt key, or meta key, is held.
+    pub alt: bool,
+}
+
+impl KeyChord {
+    /// Creates a key press without modifiers.
+    #[must_use]
+    pub const fn new(key: Key) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            alt: false,
+        }
+    }
+
+    /// Creates a key press of a character without modifiers.
+    #[must_use]
+    pub const fn char(c: char) -> Self {
+        Self::new(Key::Char(c))
+    }
+
+    /// Creates a key press of a character with the control key held, e.g. `C-n`.
+    #[must_use]
+    pub const fn ctrl(c: char) -> Self {
+        Self {
+            key: Key::Char(c),
+            ctrl: true,
+            alt: false,
+        }
+    }
+
+    /// Creates a key press of a character with the alt key held, e.g. `M-<`.
+    #[must_use]
+    pub const fn alt(c: char) -> Self {
+        Self {
+            key: Key::Char(c),
+            ctrl: false,
+            alt: true,
+        }
+    }
+}
+
+impl From<Key> for KeyChord {
+    fn from(key: Key) -> Self {
+        Self::new(key)
+    }
+}
+
+impl TryFrom<KeyEvent> for KeyChord {
+    type Error = ();
+
+    /// Converts a key press of the crossterm backend. Fails for key releases
+    /// and for keys that can not be bound.
+    fn try_from(event: KeyEvent) -> Result<Self, Self::Error> {
+        if event.kind == KeyEventKind::Release {
+            return Err(());
+        }
+        let key = match event.code {
+            KeyCode::Char(c) => Key::Char(c),
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::Home => Key::Home,
+            KeyCode::End => Key::End,
+            KeyCode::PageUp => Key::PageUp,
+            KeyCode::PageDown => Key::PageDown,
+            _ => return Err(()),
+        };
+        Ok(Self {
+            key,
+            ctrl: event.modifiers.contains(KeyModifiers::CONTROL),
+            alt: event.modifiers.contains(KeyModifiers::ALT),
+        })
+    }
+}
+
+/// A navigation command of a list, see [`ListState::execute`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListCommand {
+    /// Selects the next item, see [`ListState::next`].
+    Next,
+
+    /// Selects the previous item, see [`ListState::previous`].
+    Previous,
+
+    /// Selects the first item, see [`ListState::select_first`].
+    First,
+
+    /// Selects the last item, see [`ListState::select_last`].
+    Last,
+
+    /// Moves the selection one page down, see [`ListState::next_page`].
+    NextPage,
+
+    /// Moves the selection one page up, see [`ListState::previous_page`].
+    PreviousPage,
+
+    /// Moves the selection half a page down.
+    NextHalfPage,
+
+    /// Moves the selection half a page up.
+    PreviousHalfPage,
+}
+
+/// A sequence of key presses that triggers a [`ListCommand`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    /// The key presses, e.g. `g` followed by `g`.
+    pub keys: Cow<'static, [KeyChord]>,
+
+    /// The triggered command.
+    pub command: ListCommand,
+}
+
+impl KeyBinding {
+    /// Creates a binding of a sequence of key presses to a command.
+    #[must_use]
+    pub const fn new(keys: &'static [KeyChord], command: ListCommand) -> Self {
+        Self {
+            keys: Cow::Borrowed(keys),
+            command,
+        }
+    }
+}
+
+/// Maps keys to the navigation commands of a list, such that applications do
+/// not have to write the same match block by hand. The keys are handled with
+/// [`ListState::handle_key`].
+///
+/// Bindings may consist of several key presses, e.g. `gg` in vim. The keymap
+/// is independent of the terminal backend: the key events of the backend are
+/// converted into [`KeyChord`]s by the application.
+///
+/// [`ListKeymap::vim`] and [`ListKeymap::emacs`] are ready-made presets, and
+/// [`ListKeymap::default`] binds the arrow, home, end and page keys.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+/// use tui_widget_list::{KeyChord, ListBuilder, ListCommand, ListKeymap, ListState, ListView};
+///
+/// const KEYMAP: ListKeymap = ListKeymap::vim();
+///
+/// let area = Rect::new(0, 0, 5, 3);
+/// let mut state = ListState::default();
+/// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+/// ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+///
+/// assert_eq!(state.handle_key(KeyChord::char('G'), &KEYMAP), Some(ListCommand::Last));
+/// assert_eq!(state.handle_key(KeyChord::char('g'), &KEYMAP), None);
+/// assert_eq!(state.handle_key(KeyChord::char('g'), &KEYMAP), Some(ListCommand::First));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListKeymap {
+    bindings: Cow<'static, [KeyBinding]>,
+}
+
+/// The bindings of the arrow, home, end and page keys.
+const DEFAULT_BINDINGS: &[KeyBinding] = &[
+    KeyBinding::new(&[KeyChord::new(Key::Down)], ListCommand::Next),
+    KeyBinding::new(&[KeyChord::new(Key::Up)], ListCommand::Previous),
+    KeyBinding::new(&[KeyChord::new(Key::Right)], ListCommand::Next),
+    KeyBinding::new(&[KeyChord::new(Key::Left)], ListCommand::Previous),
+    KeyBinding::new(&[KeyChord::new(Key::Home)], ListCommand::First),
+    KeyBinding::new(&[KeyChord::new(Key::End)], ListCommand::Last),
+    KeyBinding::new(&[KeyChord::new(Key::PageDown)], ListCommand::NextPage),
+    KeyBinding::new(&[KeyChord::new(Key::PageUp)], ListCommand::PreviousPage),
+];
+
+/// The bindings of vim.
+const VIM_BINDINGS: &[KeyBinding] = &[
+    KeyBinding::new(&[KeyChord::char('j')], ListCommand::Next),
+    KeyBinding::new(&[KeyChord::char('k')], ListCommand::Previous),
+    KeyBinding::new(
+        &[KeyChord::char('g'), KeyChord::char('g')],
+        ListCommand::First,
+    ),
+    KeyBinding::new(&[KeyChord::char('G')], ListCommand::Last),
+    KeyBinding::new(&[KeyChord::ctrl('f')], ListCommand::NextPage),
+    KeyBinding::new(&[KeyChord::ctrl('b')], ListCommand::PreviousPage),
+    KeyBinding::new(&[KeyChord::ctrl('d')], ListCommand::NextHalfPage),
+    KeyBinding::new(&[KeyChord::ctrl('u')], ListCommand::PreviousHalfPage),
+];
+
+/// The bindings of emacs.
+const EMACS_BINDINGS: &[KeyBinding] = &[
+    KeyBinding::new(&[KeyChord::ctrl('n')], ListCommand::Next),
+    KeyBinding::new(&[KeyChord::ctrl('p')], ListCommand::Previous),
+    KeyBinding::new(&[KeyChord::alt('<')], ListCommand::First),
+    KeyBinding::new(&[KeyChord::alt('>')], ListCommand::Last),
+    KeyBinding::new(&[KeyChord::ctrl('v')], ListCommand::NextPage),
+    KeyBinding::new(&[KeyChord::alt('v')], ListCommand::PreviousPage),
+];
+
+/// The result of looking up a key sequence in a [`ListKeymap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Lookup {
+    /// The sequence triggers the command.
+    Command(ListCommand),
+
+    /// The sequence is the start of a longer binding.
+    Pending,
+
+    /// The sequence is not bound.
+    Unbound,
+}
+
+impl Default for ListKeymap {
+    fn default() -> Self {
+        Self {
+            bindings: Cow::Borrowed(DEFAULT_BINDINGS),
+        }
+    }
+}
+
+impl ListKeymap {
+    /// Creates a keymap without bindings.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            bindings: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// Returns the vim preset: `j` and `k` select the next and previous item,
+    /// `gg` and `G` the first and last item, `C-f` and `C-b` move by a page,
+    /// and `C-d` and `C-u` by half a page.
+    #[must_use]
+    pub const fn vim() -> Self {
+        Self {
+            bindings: Cow::Borrowed(VIM_BINDINGS),
+        }
+    }
+
+    /// Returns the emacs preset: `C-n` and `C-p` select the next and previous
+    /// item, `M-<` and `M->` the first and last item, and `C-v` and `M-v` move
+    /// by a page.
+    #[must_use]
+    pub const fn emacs() -> Self {
+        Self {
+            bindings: Cow::Borrowed(EMACS_BINDINGS),
+        }
+    }
+
+    /// Adds a binding of a sequence of key presses to a command. Existing
+    /// bindings of the same sequence are replaced.
+    ///
+    /// # Example
+    /// ```
+    /// use tui_widget_list::{Key, KeyChord, ListCommand, ListKeymap};
+    ///
+    /// let keymap = ListKeymap::vim()
+    ///     .bind([KeyChord::new(Key::Down)], ListCommand::Next)
+    ///     .bind([KeyChord::new(Key::Up)], ListCommand::Previous);
+    /// ```
+    #[must_use]
+    pub fn bind<I>(mut self, keys: I, command: ListCommand) -> Self
+    where
+        I: IntoIterator<Item = KeyChord>,
+    {
+        let keys: Vec<KeyChord> = keys.into_iter().collect();
+        let bindings = self.bindings.to_mut();
+        bindings.retain(|binding| *binding.keys != *keys);
+        bindings.push(KeyBinding {
+            keys: Cow::Owned(keys),
+            command,
+        });
+        self
+    }
+
+    /// Returns the bindings of the keymap.
+    #[must_use]
+    pub fn bindings(&self) -> &[KeyBinding] {
+        &self.bindings
+    }
+
+    /// Looks up a sequence of key presses.
+    pub(crate) fn lookup(&self, keys: &[KeyChord]) -> Lookup {
+        let mut lookup = Lookup::Unbound;
+        for binding in self.bindings.iter() {
+            if *binding.keys == *keys {
+                return Lookup::Command(binding.command);
+            }
+            if binding.keys.starts_with(keys) {
+                lookup = Lookup::Pending;
+            }
+        }
+        lookup
+    }
+}
+
+impl ListState {
+    /// Handles a key press with the given keymap. If the key completes a
+    /// binding, the command is executed and returned. If the key starts a
+    /// binding of several keys, e.g. the first `g` of `gg`, it is kept until
+    /// the next key press and `None` is returned.
+    ///
+    /// Returns `None` if the key is not bound, such that the application can
+    /// handle it otherwise.
+    pub fn handle_key(&mut self, key: KeyChord, keymap: &ListKeymap) -> Option<ListCommand> {
+        self.pending_keys.push(key);
+        match keymap.lookup(&self.pending_keys) {
+            Lookup::Command(command) => {
+                self.pending_keys.clear();
+                self.execute(command);
+                Some(command)
+            }
+            Lookup::Pending => None,
+            // Start over with the key if it did not continue the pending keys.
+            Lookup::Unbound if self.pending_keys.len() > 1 => {
+                self.pending_keys.clear();
+                self.handle_key(key, keymap)
+            }
+            Lookup::Unbound => {
+                self.pending_keys.clear();
+                None
+            }
+        }
+    }
+
+    /// Handles a key event of the crossterm backend with the given keymap, see
+    /// [`ListState::handle_key`]. Key releases are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::crossterm::event::{KeyCode, KeyEvent};
+    /// use tui_widget_list::{ListCommand, ListKeymap, ListState};
+    ///
+    /// let mut state = ListState::default();
+    /// let keymap = ListKeymap::vim();
+    ///
+    /// let command = state.handle_key_event(KeyEvent::from(KeyCode::Char('G')), &keymap);
+    /// assert_eq!(command, Some(ListCommand::Last));
+    /// ```
+    pub fn handle_key_event(
+        &mut self,
+        event: KeyEvent,
+        keymap: &ListKeymap,
+    ) -> Option<ListCommand> {
+        let key = KeyChord::try_from(event).ok()?;
+        self.handle_key(key, keymap)
+    }
+
+    /// Executes a navigation command.
+    pub fn execute(&mut self, command: ListCommand) {
+        match command {
+            ListCommand::Next => self.next(),
+            ListCommand::Previous => self.previous(),
+            ListCommand::First => self.select_first(),
+            ListCommand::Last => self.select_last(),
+            ListCommand::NextPage => self.next_page(),
+            ListCommand::PreviousPage => self.previous_page(),
+            ListCommand::NextHalfPage => self.move_by_half_page(true),
+            ListCommand::PreviousHalfPage => self.move_by_half_page(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_keys() {
+        // given
+        let keymap = ListKeymap::vim();
+        let mut state = ListState::default();
+        state.set_num_elements(10);
+        state.select(Some(5));
+
+        // when
+        let commands = [
+            state.handle_key(KeyChord::char('g'), &keymap),
+            state.handle_key(KeyChord::char('j'), &keymap),
+            state.handle_key(KeyChord::char('g'), &keymap),
+            state.handle_key(KeyChord::char('x'), &keymap),
+            state.handle_key(KeyChord::char('g'), &keymap),
+            state.handle_key(KeyChord::char('g'), &keymap),
+        ];
+
+        // then
+        assert_eq!(
+            commands,
+            [
+                None,
+                Some(ListCommand::Next),
+                None,
+                None,
+                None,
+                Some(ListCommand::First)
+            ]
+        );
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn key_events() {
+        // given
+        let keymap = ListKeymap::emacs();
+        let mut state = ListState::default();
+        state.set_num_elements(10);
+        state.select(Some(5));
+        let mut release = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+        release.kind = KeyEventKind::Release;
+
+        // when
+        let commands = [
+            state.handle_key_event(
+                KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL),
+                &keymap,
+            ),
+            state.handle_key_event(release, &keymap),
+            state.handle_key_event(KeyEvent::from(KeyCode::Esc), &keymap),
+        ];
+
+        // then
+        assert_eq!(commands, [Some(ListCommand::Next), None, None]);
+        assert_eq!(state.selected, Some(6));
+    }
+
+    #[test]
+    fn presets_with_key_events() {
+        // given
+        let mut state = ListState::default();
+        state.set_num_elements(10);
+        state.select(Some(5));
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+
+        // when
+        let commands = [
+            state.handle_key_event(
+                key(KeyCode::Char('G'), KeyModifiers::SHIFT),
+                &ListKeymap::vim(),
+            ),
+            state.handle_key_event(
+                key(KeyCode::Char('<'), KeyModifiers::ALT | KeyModifiers::SHIFT),
+                &ListKeymap::emacs(),
+            ),
+            state.handle_key_event(
+                key(KeyCode::Char('g'), KeyModifiers::NONE),
+                &ListKeymap::vim(),
+            ),
+            state.handle_key_event(
+                key(KeyCode::Char('j'), KeyModifiers::NONE),
+                &ListKeymap::vim(),
+            ),
+        ];
+
+        // then
+        assert_eq!(
+            commands,
+            [
+                Some(ListCommand::Last),
+                Some(ListCommand::First),
+                None,
+                Some(ListCommand::Next)
+            ]
+        );
+        assert_eq!(state.selected, Some(1));
+    }
+
+    #[test]
+    fn emacs() {
+        // given
+        let keymap = ListKeymap::emacs();
+        let mut state = ListState::default();
+        state.set_num_elements(10);
+
+        // when
+        state.handle_key(KeyChord::alt('>'), &keymap);
+        state.handle_key(KeyChord::ctrl('p'), &keymap);
+
+        // then
+        assert_eq!(state.selected, Some(8));
+    }
+
+    #[test]
+    fn bind_replaces() {
+        // given
+        let keymap = ListKeymap::default().bind([KeyChord::new(Key::Down)], ListCommand::Last);
+
+        // then
+        assert_eq!(
+            keymap.lookup(&[KeyChord::new(Key::Down)]),
+            Lookup::Command(ListCommand::Last)
+        );
+        assert_eq!(keymap.bindings().len(), DEFAULT_BINDINGS.len());
+    }
+}
@@ -1,9 +1,20 @@
+//! The layout engine that determines which items are shown on the viewport.
+//!
+//! The list lays out its items lazily: starting from the previous viewport
+//! position, only the items that are needed to fill the viewport are built.
+//! [`layout_on_viewport`] updates the viewport position in the [`ListState`]
+//! and returns a [`ViewportElement`] for every visible item, including how
+//! much of the item is truncated at the edges of the viewport.
+//!
+//! The layout is what [`ListView`] renders. It is exposed for custom widgets
+//! that build on the same scrolling behavior, e.g. to render the items
+//! differently or to compute overlays.
+//!
+//! [`ListView`]: crate::ListView
+use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fmt::Debug;
-use std::io::Write;
-use std::{cmp::Ordering, fs::OpenOptions};
 
-use crate::{view::Truncation, ListBuildContext, ListBuilder, ListState, ScrollAxis};
+use crate::{ItemCache, ListBuildContext, ListBuilder, ListState, ScrollAxis};
 
 /// Determines the new viewport layout based on the previous viewport state, i.e.
 /// the offset of the first element and the truncation of the first element.
@@ -22,8 +33,11 @@ use crate::{view::Truncation, ListBuildContext, ListBuilder, ListState, ScrollAx
 /// - Selected item is below the previous viewport, either truncated or out of bounds
 ///      - If it is truncated, the viewport will be adjusted to bring the entire item into view.
 ///      - If it is out of bounds, the viewport will be scrolled downwards to make the selected item visible.
-#[allow(clippy::too_many_lines)]
-pub(crate) fn layout_on_viewport<T>(
+///
+/// The items are built with `builder`, consulting `item_cache` first if any.
+/// Returns the visible items by their index.
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
+pub fn layout_on_viewport<T>(
     state: &mut ListState,
     builder: &ListBuilder<T>,
     item_count: usize,
@@ -31,24 +45,42 @@ pub(crate) fn layout_on_viewport<T>(
     cross_axis_size: u16,
     scroll_axis: ScrollAxis,
     scroll_padding: u16,
+    item_cache: Option<&mut dyn ItemCache<T>>,
 ) -> HashMap<usize, ViewportElement<T>> {
     // Cache the widgets and sizes to evaluate the builder less often.
-    let mut cacher = WidgetCacher::new(builder, scroll_axis, cross_axis_size, state.selected);
+    let mut cacher = WidgetCacher::new(
+        builder,
+        item_cache,
+        scroll_axis,
+        total_main_axis_size,
+        cross_axis_size,
+        state.selected,
+    );
 
     // The items heights on the viewport will be calculated on the fly.
+    // Unlike the scroll padding buffer, this map holds the typed widgets and
+    // is handed to the caller, so it cannot be kept in the `ListState`.
     let mut viewport: HashMap<usize, ViewportElement<T>> = HashMap::new();
 
-    // If none is selected, the first item should be show on top of the viewport.
-    let selected = state.selected.unwrap_or(0);
+    // If none is selected, the first item should be show on top of the viewport,
+    // unless the offset is anchored.
+    let selected = match state.selected {
+        Some(selected) => selected,
+        None if state.offset_anchored => state.view_state.offset.min(item_count.saturating_sub(1)),
+        None => 0,
+    };
 
     // Calculate the effective scroll padding for each widget
+    let scroll_padding_buffer = std::mem::take(&mut state.scroll_padding_buffer);
     let effective_scroll_padding_by_index = calculate_effective_scroll_padding(
         state,
         builder,
         item_count,
+        total_main_axis_size,
         cross_axis_size,
         scroll_axis,
         scroll_padding,
+        scroll_padding_buffer,
     );
 
     update_offset(
@@ -70,26 +102,27 @@ pub(crate) fn layout_on_viewport<T>(
         &effective_scroll_padding_by_index,
     );
 
-    if found_selected {
-        return viewport;
-    }
-
-    for (key, value) in viewport.drain() {
-        cacher.insert(key, value.widget, value.main_axis_size);
+    if !found_selected {
+        // The widgets of the forward pass were built with their position on
+        // the previous viewport, so they are rebuilt in the backward pass.
+        viewport.clear();
+
+        // Perform a backward pass, starting from the `selected` item.
+        // This step is only necessary if the forward pass did not
+        // locate the selected item.
+        backward_pass(
+            &mut viewport,
+            state,
+            &mut cacher,
+            item_count,
+            total_main_axis_size,
+            selected,
+            &effective_scroll_padding_by_index,
+        );
     }
 
-    // Perform a backward pass, starting from the `selected` item.
-    // This step is only necessary if the forward pass did not
-    // locate the selected item.
-    backward_pass(
-        &mut viewport,
-        state,
-        &mut cacher,
-        item_count,
-        total_main_axis_size,
-        selected,
-        &effective_scroll_padding_by_index,
-    );
+    // Keep the allocation for the next frame.
+    state.scroll_padding_buffer = effective_scroll_padding_by_index;
 
     viewport
 }
@@ -99,7 +132,7 @@ pub(crate) fn layout_on_viewport<T>(
 // part is that we also need to account for scroll padding.
 fn update_offset<T>(
     state: &mut ListState,
-    cacher: &mut WidgetCacher<T>,
+    cacher: &mut WidgetCacher<'_, '_, T>,
     selected: usize,
     scroll_padding_by_index: &HashMap<usize, u16>,
 ) {
@@ -149,7 +182,7 @@ fn update_offset<T>(
 fn forward_pass<T>(
     viewport: &mut HashMap<usize, ViewportElement<T>>,
     state: &mut ListState,
-    cacher: &mut WidgetCacher<T>,
+    cacher: &mut WidgetCacher<'_, '_, T>,
     offset: usize,
     item_count: usize,
     total_main_axis_size: u16,
@@ -163,7 +196,14 @@ fn forward_pass<T>(
     for index in offset..item_count {
         let is_first = index == state.view_state.offset;
 
-        let (widget, total_main_axis_size) = cacher.get(index);
+        // The space that remains from the start of the item to the end of the viewport.
+        let remaining_size = if is_first {
+            available_size.saturating_add(state.view_state.first_truncated)
+        } else {
+            available_size
+        };
+        let (widget, total_main_axis_size) =
+            cacher.get_with_remaining(index, remaining_size, index - offset);
 
         let main_axis_size = if is_first {
             total_main_axis_size.saturating_sub(state.view_state.first_truncated)
@@ -237,27 +277,43 @@ fn forward_pass<T>(
 fn backward_pass<T>(
     viewport: &mut HashMap<usize, ViewportElement<T>>,
     state: &mut ListState,
-    cacher: &mut WidgetCacher<T>,
+    cacher: &mut WidgetCacher<'_, '_, T>,
     item_count: usize,
     total_main_axis_size: u16,
     selected: usize,
     scroll_padding_by_index: &HashMap<usize, u16>,
 ) {
+    // The items are measured first and, for positional builders, built again
+    // once the first visible item, and thus their position on the viewport, is known.
+    let mut layout = Vec::new();
     let mut found_first = false;
     let mut available_size = total_main_axis_size;
     let scroll_padding_effective = *scroll_padding_by_index.get(&selected).unwrap_or(&0);
+
+    // The separator behind the selected item may be cut off at the end of the
+    // viewport, as long as the item itself is fully visible.
+    let cut_separator = u16::from(
+        scroll_padding_effective == 0 && cacher.builder.separated && selected + 1 < item_count,
+    );
     for index in (0..=selected).rev() {
-        let (widget, main_axis_size) = cacher.get(index);
+        let main_axis_size = cacher.get_height(index);
+        let cut = if index == selected { cut_separator } else { 0 };
+        let fit_size = main_axis_size.saturating_sub(cut);
+        let cut_truncation = if cut > 0 {
+            Truncation::Bot(cut)
+        } else {
+            Truncation::None
+        };
 
         let available_effective = available_size.saturating_sub(scroll_padding_effective);
 
-        let truncation = match available_effective.cmp(&main_axis_size) {
+        let truncation = match available_effective.cmp(&fit_size) {
             // We found the first element and it fits into the viewport
             Ordering::Equal => {
                 found_first = true;
                 state.view_state.offset = index;
                 state.view_state.first_truncated = 0;
-                Truncation::None
+                cut_truncation
             }
             // We found the first element but it needs to be truncated
             Ordering::Less => {
@@ -272,17 +328,15 @@ fn backward_pass<T>(
                     Truncation::Top(state.view_state.first_truncated)
                 }
             }
-            Ordering::Greater => Truncation::None,
+            Ordering::Greater => cut_truncation,
         };
-
-        let element = ViewportElement::new(widget, main_axis_size, truncation);
-        viewport.insert(index, element);
+        layout.push((index, main_axis_size, truncation));
 
         if found_first {
             break;
         }
 
-        available_size -= main_axis_size;
+        available_size -= fit_size;
     }
 
     // Append elements to the list to fill the viewport after the selected item.
@@ -290,16 +344,13 @@ fn backward_pass<T>(
     if scroll_padding_effective > 0 {
         available_size = scroll_padding_effective;
         for index in selected + 1..item_count {
-            let (widget, main_axis_size) = cacher.get(index);
+            let main_axis_size = cacher.get_height(index);
 
             let truncation = match available_size.cmp(&main_axis_size) {
                 Ordering::Greater | Ordering::Equal => Truncation::None,
                 Ordering::Less => Truncation::Bot(main_axis_size.saturating_sub(available_size)),
             };
-            viewport.insert(
-                index,
-                ViewportElement::new(widget, main_axis_size, truncation),
-            );
+            layout.push((index, main_axis_size, truncation));
 
             available_size = available_size.saturating_sub(main_axis_size);
             // Out of bounds
@@ -308,6 +359,19 @@ fn backward_pass<T>(
             }
         }
     }
+
+    // Build the visible items with their position on the viewport.
+    layout.sort_unstable_by_key(|&(index, _, _)| index);
+    let offset = state.view_state.offset;
+    let mut remaining_size = total_main_axis_size.saturating_add(state.view_state.first_truncated);
+    for (index, main_axis_size, truncation) in layout {
+        let (widget, _) = cacher.get_with_remaining(index, remaining_size, index - offset);
+        viewport.insert(
+            index,
+            ViewportElement::new(widget, main_axis_size, truncation),
+        );
+        remaining_size = remaining_size.saturating_sub(main_axis_size);
+    }
 }
 
 /// Calculate the effective scroll padding.
@@ -315,19 +379,25 @@ fn backward_pass<T>(
 /// after which elements at the beginning or end of the list do
 /// not receive padding.
 ///
+/// The map of the previous frame is passed in `padding_by_element` to reuse its
+/// allocation.
+///
 /// Returns:
 /// A `HashMap` where the keys are the indices of the list items and the values are
 /// the corresponding padding applied. If the item is not on the list, `scroll_padding`
 /// is unaltered.
+#[allow(clippy::too_many_arguments)]
 fn calculate_effective_scroll_padding<T>(
     state: &mut ListState,
     builder: &ListBuilder<T>,
     item_count: usize,
+    viewport_main_axis_size: u16,
     cross_axis_size: u16,
     scroll_axis: ScrollAxis,
     scroll_padding: u16,
+    mut padding_by_element: HashMap<usize, u16>,
 ) -> HashMap<usize, u16> {
-    let mut padding_by_element = HashMap::new();
+    padding_by_element.clear();
     let mut total_main_axis_size = 0;
 
     for index in 0..item_count {
@@ -337,14 +407,14 @@ fn calculate_effective_scroll_padding<T>(
         }
         padding_by_element.insert(index, total_main_axis_size);
 
-        let context = ListBuildContext {
+        let context = builder.context(
             index,
-            is_selected: state.selected == Some(index),
+            state.selected == Some(index),
             scroll_axis,
             cross_axis_size,
-        };
+        );
 
-        let (_, item_main_axis_size) = builder.call_closure(&context);
+        let item_main_axis_size = builder.main_axis_size(&context, viewport_main_axis_size);
         total_main_axis_size += item_main_axis_size;
     }
 
@@ -356,65 +426,79 @@ fn calculate_effective_scroll_padding<T>(
         }
         padding_by_element.insert(index, total_main_axis_size);
 
-        let context = ListBuildContext {
+        let context = builder.context(
             index,
-            is_selected: state.selected == Some(index),
+            state.selected == Some(index),
             scroll_axis,
             cross_axis_size,
-        };
+        );
 
-        let (_, item_main_axis_size) = builder.call_closure(&context);
+        let item_main_axis_size = builder.main_axis_size(&context, viewport_main_axis_size);
         total_main_axis_size += item_main_axis_size;
     }
 
     padding_by_element
 }
 
-struct WidgetCacher<'a, T> {
-    cache: HashMap<usize, (T, u16)>,
+struct WidgetCacher<'a, 'c, T> {
+    cache: HashMap<usize, (Option<T>, u16)>,
     builder: &'a ListBuilder<'a, T>,
+    item_cache: Option<&'c mut dyn ItemCache<T>>,
     scroll_axis: ScrollAxis,
+    total_main_axis_size: u16,
     cross_axis_size: u16,
     selected: Option<usize>,
 }
 
-impl<'a, T> WidgetCacher<'a, T> {
+impl<'a, 'c, T> WidgetCacher<'a, 'c, T> {
     // Create a new WidgetCacher
     fn new(
         builder: &'a ListBuilder<'a, T>,
+        item_cache: Option<&'c mut dyn ItemCache<T>>,
         scroll_axis: ScrollAxis,
+        total_main_axis_size: u16,
         cross_axis_size: u16,
         selected: Option<usize>,
     ) -> Self {
         Self {
             cache: HashMap::new(),
             builder,
+            item_cache,
             scroll_axis,
+            total_main_axis_size,
             cross_axis_size,
             selected,
         }
     }
 
-    // Gets the widget and the height. Removes the widget from the cache.
-    fn get(&mut self, index: usize) -> (T, u16) {
-        let is_selected = self.selected == Some(index);
-        // Check if the widget is already in cache
-        if let Some((widget, main_axis_size)) = self.cache.remove(&index) {
-            return (widget, main_axis_size);
+    // Gets the widget and the height. Positional builders are always invoked
+    // with the remaining main axis size and the position on the viewport, so
+    // their cached widgets are discarded.
+    fn get_with_remaining(
+        &mut self,
+        index: usize,
+        remaining_main_axis_size: u16,
+        viewport_index: usize,
+    ) -> (Option<T>, u16) {
+        let cached = self.cache.remove(&index);
+        if let Some(cached) = cached.filter(|_| !self.builder.positional) {
+            return cached;
         }
 
         // Create the context for the builder
         let context = ListBuildContext {
-            index,
-            is_selected,
-            scroll_axis: self.scroll_axis,
-            cross_axis_size: self.cross_axis_size,
+            remaining_main_axis_size: Some(remaining_main_axis_size),
+            viewport_index: Some(viewport_index),
+            ..self.builder.context(
+                index,
+                self.selected == Some(index),
+                self.scroll_axis,
+                self.cross_axis_size,
+            )
         };
 
         // Call the builder to get the widget
-        let (widget, main_axis_size) = self.builder.call_closure(&context);
-
-        (widget, main_axis_size)
+        self.build(&context)
     }
 
     // Gets the height.
@@ -426,15 +510,12 @@ impl<'a, T> WidgetCacher<'a, T> {
         }
 
         // Create the context for the builder
-        let context = ListBuildContext {
-            index,
-            is_selected,
-            scroll_axis: self.scroll_axis,
-            cross_axis_size: self.cross_axis_size,
-        };
+        let context =
+            self.builder
+                .context(index, is_selected, self.scroll_axis, self.cross_axis_size);
 
         // Call the builder to get the widget
-        let (widget, main_axis_size) = self.builder.call_closure(&context);
+        let (widget, main_axis_size) = self.build(&context);
 
         // Store the widget in the cache
         self.cache.insert(index, (widget, main_axis_size));
@@ -442,40 +523,185 @@ impl<'a, T> WidgetCacher<'a, T> {
         main_axis_size
     }
 
-    fn insert(&mut self, index: usize, widget: T, main_axis_size: u16) {
-        self.cache.insert(index, (widget, main_axis_size));
+    // Calls the builder, consulting the item cache first. Items that are not
+    // loaded yet are represented by a placeholder without a widget.
+    fn build(&mut self, context: &ListBuildContext) -> (Option<T>, u16) {
+        if let Some(main_axis_size) = self.builder.placeholder_size(context.index) {
+            return (None, main_axis_size);
+        }
+        if self.builder.defer(context.index) {
+            return (None, self.builder.placeholder_size.unwrap_or(1));
+        }
+        let item_cache = self
+            .item_cache
+            .as_mut()
+            .map(|item_cache| &mut **item_cache as &mut dyn ItemCache<T>);
+        let (widget, main_axis_size) =
+            self.builder
+                .call_closure_cached(context, self.total_main_axis_size, item_cache);
+        (Some(widget), main_axis_size)
     }
 }
 
-#[allow(dead_code)]
-pub fn log_to_file<T: Debug>(data: T) {
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open("debug.log")
-        .unwrap();
+/// Caches the cumulative main axis sizes of the items, i.e. the start
+/// position of each item measured from the start of the list.
+///
+/// The sizes are evaluated lazily and are only valid for a given selection
+/// and viewport, which are tracked by [`SizeCacheKey`]. The cache is kept
+/// valid incrementally where possible: appending items keeps the offsets of
+/// the existing items, and a change of the selection only drops the offsets
+/// behind the previously or newly selected item, whichever comes first. Any
+/// other change of the item sizes clears the cache through
+/// [`ListState::invalidate_item_offsets`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SizeCache {
+    /// The n-th entry is the start position of the n-th item.
+    offsets: Vec<usize>,
+
+    /// The parameters for which the cached offsets are valid.
+    key: Option<SizeCacheKey>,
+}
+
+/// The parameters that affect the sizes returned by the builder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SizeCacheKey {
+    pub(crate) selected: Option<usize>,
+    pub(crate) scroll_axis: ScrollAxis,
+    pub(crate) main_axis_size: u16,
+    pub(crate) cross_axis_size: u16,
+}
 
-    if let Err(e) = writeln!(file, "{data:?}") {
-        eprintln!("Couldn't write to file: {e}");
+impl SizeCache {
+    /// Returns the start position of the item at `index`. Indices past the
+    /// end of the list return the total size of the list.
+    pub(crate) fn offset<T>(
+        &mut self,
+        builder: &ListBuilder<T>,
+        item_count: usize,
+        index: usize,
+        key: SizeCacheKey,
+    ) -> usize {
+        match self.key {
+            Some(old)
+                if SizeCacheKey {
+                    selected: key.selected,
+                    ..old
+                } == key =>
+            {
+                // Only the sizes of the previously and the newly selected item changed.
+                if let Some(first) = old.selected.into_iter().chain(key.selected).min() {
+                    if old.selected != key.selected {
+                        self.offsets.truncate(first + 1);
+                    }
+                }
+            }
+            _ => self.offsets.clear(),
+        }
+        self.key = Some(key);
+
+        // Drop the offsets of items that no longer exist.
+        self.offsets.truncate(item_count + 1);
+        if self.offsets.is_empty() {
+            self.offsets.push(0);
+        }
+
+        let index = index.min(item_count);
+        while self.offsets.len() <= index {
+            let last_index = self.offsets.len() - 1;
+            let context = builder.context(
+                last_index,
+                key.selected == Some(last_index),
+                key.scroll_axis,
+                key.cross_axis_size,
+            );
+            let main_axis_size = builder.main_axis_size(&context, key.main_axis_size);
+            self.offsets
+                .push(self.offsets[last_index] + usize::from(main_axis_size));
+        }
+
+        self.offsets[index]
     }
 }
 
+/// An item on the viewport, see [`layout_on_viewport`].
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
-pub(crate) struct ViewportElement<T> {
-    pub(crate) widget: T,
+pub struct ViewportElement<T> {
+    /// The widget of the item, or `None` for a placeholder.
+    pub(crate) widget: Option<T>,
+
+    /// The full size of the item along the main axis.
     pub(crate) main_axis_size: u16,
+
+    /// How much of the item is cut off at the edges of the viewport.
     pub(crate) truncation: Truncation,
 }
 
 impl<T> ViewportElement<T> {
     #[must_use]
-    pub(crate) fn new(widget: T, main_axis_size: u16, truncation: Truncation) -> Self {
+    pub(crate) fn new(widget: Option<T>, main_axis_size: u16, truncation: Truncation) -> Self {
         Self {
             widget,
             main_axis_size,
             truncation,
         }
     }
+
+    /// Returns the widget of the item, or `None` if the item is rendered as a
+    /// placeholder because it is not loaded yet.
+    #[must_use]
+    pub fn widget(&self) -> Option<&T> {
+        self.widget.as_ref()
+    }
+
+    /// Consumes the element and returns its widget, see [`ViewportElement::widget`].
+    #[must_use]
+    pub fn into_widget(self) -> Option<T> {
+        self.widget
+    }
+
+    /// Returns the full size of the item along the main axis, including the
+    /// truncated part.
+    #[must_use]
+    pub fn main_axis_size(&self) -> u16 {
+        self.main_axis_size
+    }
+
+    /// Returns how much of the item is cut off at the edges of the viewport.
+    #[must_use]
+    pub fn truncation(&self) -> &Truncation {
+        &self.truncation
+    }
+
+    /// Returns the size of the visible part of the item along the main axis.
+    #[must_use]
+    pub fn visible_main_axis_size(&self) -> u16 {
+        self.main_axis_size.saturating_sub(self.truncation.value())
+    }
+}
+
+/// Describes how much of an item is cut off at the edges of the viewport.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum Truncation {
+    /// The item is fully visible.
+    #[default]
+    None,
+
+    /// The given number of rows or columns are cut off at the start of the item.
+    Top(u16),
+
+    /// The given number of rows or columns are cut off at the end of the item.
+    Bot(u16),
+}
+
+impl Truncation {
+    /// Returns the number of rows or columns that are cut off.
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        match self {
+            Self::Top(value) | Self::Bot(value) => *value,
+            Self::None => 0,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -536,8 +762,14 @@ mod tests {
             first_truncated: 0,
         };
         let expected_viewport = HashMap::from([
-            (0, ViewportElement::new(TestItem {}, 2, Truncation::None)),
-            (1, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
         ]);
 
         // when
@@ -551,6 +783,7 @@ mod tests {
             1,
             ScrollAxis::Vertical,
             0,
+            None,
         );
 
         // then
@@ -595,8 +828,14 @@ mod tests {
             first_truncated: 0,
         };
         let expected_viewport = HashMap::from([
-            (0, ViewportElement::new(TestItem {}, 2, Truncation::None)),
-            (1, ViewportElement::new(TestItem {}, 2, Truncation::Bot(1))),
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Bot(1)),
+            ),
         ]);
 
         // when
@@ -610,6 +849,7 @@ mod tests {
             1,
             ScrollAxis::Vertical,
             0,
+            None,
         );
 
         // then
@@ -649,8 +889,14 @@ mod tests {
             first_truncated: 1,
         };
         let expected_viewport = HashMap::from([
-            (0, ViewportElement::new(TestItem {}, 2, Truncation::Top(1))),
-            (1, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Top(1)),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
         ]);
 
         // when
@@ -664,6 +910,104 @@ mod tests {
             1,
             ScrollAxis::Vertical,
             0,
+            None,
+        );
+
+        // then
+        assert_eq!(viewport, expected_viewport);
+        assert_eq!(state.view_state, expected_view_state);
+    }
+
+    // The horizontal counterpart of `scroll_up`: the first item is truncated
+    // on the left and is brought into view again when it is selected.
+    #[test]
+    fn scroll_left() {
+        // given
+        let view_state = ViewState {
+            offset: 0,
+            first_truncated: 1,
+        };
+        let mut state = ListState {
+            num_elements: 3,
+            selected: Some(0),
+            view_state,
+            ..ListState::default()
+        };
+        let given_sizes = [2, 2];
+        let given_total_size = 3;
+        let given_item_count = given_sizes.len();
+
+        let expected_view_state = ViewState {
+            offset: 0,
+            first_truncated: 0,
+        };
+        let expected_viewport = HashMap::from([
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Bot(1)),
+            ),
+        ]);
+
+        // when
+        let viewport = layout_on_viewport(
+            &mut state,
+            &ListBuilder::new(move |context| (TestItem {}, given_sizes[context.index])),
+            given_item_count,
+            given_total_size,
+            1,
+            ScrollAxis::Horizontal,
+            0,
+            None,
+        );
+
+        // then
+        assert_eq!(viewport, expected_viewport);
+        assert_eq!(state.view_state, expected_view_state);
+    }
+
+    // The horizontal counterpart of `scroll_down`: the first item is truncated
+    // on the left to bring the selected second item into view.
+    #[test]
+    fn scroll_right() {
+        // given
+        let mut state = ListState {
+            num_elements: 2,
+            selected: Some(1),
+            ..ListState::default()
+        };
+        let given_sizes = [2, 2];
+        let given_item_count = given_sizes.len();
+        let given_total_size = 3;
+
+        let expected_view_state = ViewState {
+            offset: 0,
+            first_truncated: 1,
+        };
+        let expected_viewport = HashMap::from([
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Top(1)),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
+        ]);
+
+        // when
+        let viewport = layout_on_viewport(
+            &mut state,
+            &ListBuilder::new(move |context| (TestItem {}, given_sizes[context.index])),
+            given_item_count,
+            given_total_size,
+            1,
+            ScrollAxis::Horizontal,
+            0,
+            None,
         );
 
         // then
@@ -706,9 +1050,18 @@ mod tests {
             first_truncated: 1,
         };
         let expected_viewport = HashMap::from([
-            (0, ViewportElement::new(TestItem {}, 2, Truncation::Top(1))),
-            (1, ViewportElement::new(TestItem {}, 2, Truncation::None)),
-            (2, ViewportElement::new(TestItem {}, 2, Truncation::Bot(1))),
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Top(1)),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
+            (
+                2,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Bot(1)),
+            ),
         ]);
 
         // when
@@ -722,6 +1075,7 @@ mod tests {
             1,
             ScrollAxis::Vertical,
             1,
+            None,
         );
 
         // then
@@ -769,9 +1123,18 @@ mod tests {
             first_truncated: 1,
         };
         let expected_viewport = HashMap::from([
-            (0, ViewportElement::new(TestItem {}, 2, Truncation::Top(1))),
-            (1, ViewportElement::new(TestItem {}, 2, Truncation::None)),
-            (2, ViewportElement::new(TestItem {}, 2, Truncation::Bot(1))),
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Top(1)),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
+            (
+                2,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Bot(1)),
+            ),
         ]);
 
         // when
@@ -785,6 +1148,7 @@ mod tests {
             1,
             ScrollAxis::Vertical,
             1,
+            None,
         );
 
         // then
@@ -829,8 +1193,14 @@ mod tests {
             first_truncated: 0,
         };
         let expected_viewport = HashMap::from([
-            (0, ViewportElement::new(TestItem {}, 2, Truncation::None)),
-            (1, ViewportElement::new(TestItem {}, 2, Truncation::Bot(1))),
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Bot(1)),
+            ),
         ]);
 
         // when
@@ -844,6 +1214,7 @@ mod tests {
             1,
             ScrollAxis::Vertical,
             0,
+            None,
         );
 
         // then
@@ -894,9 +1265,18 @@ mod tests {
             first_truncated: 1,
         };
         let expected_viewport = HashMap::from([
-            (0, ViewportElement::new(TestItem {}, 2, Truncation::Top(1))),
-            (1, ViewportElement::new(TestItem {}, 2, Truncation::None)),
-            (2, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+            (
+                0,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::Top(1)),
+            ),
+            (
+                1,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
+            (
+                2,
+                ViewportElement::new(Some(TestItem {}), 2, Truncation::None),
+            ),
         ]);
 
         // when
@@ -910,6 +1290,7 @@ mod tests {
             1,
             ScrollAxis::Vertical,
             0,
+            None,
         );
 
         // then
@@ -932,9 +1313,11 @@ mod tests {
             &mut state,
             &builder,
             item_count,
+            10,
             1,
             ScrollAxis::Vertical,
             scroll_padding,
+            HashMap::new(),
         );
 
         assert_eq!(*scroll_padding.get(&0).unwrap(), 0);
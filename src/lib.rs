@@ -15,6 +15,34 @@
 //!
 //! - [`ListView::scroll_padding`]: Specifies whether content should remain visible while scrolling, ensuring that a specified amount of padding is preserved above/below the selected item during scrolling.
 //! - [`ListView::infinite_scrolling`]: Allows the list to wrap around when scrolling past the first or last element.
+//! - [`ListView::fit_items`]: Distributes unused space evenly among the items if all of them fit onto the viewport.
+//! - [`ListView::gutter`]: Renders a fixed-width gutter, e.g. line numbers, next to the items.
+//! - [`ListView::shortcut_hints`]: Renders the shortcut keys of the items in the gutter.
+//! - [`ListView::header`]: Renders a non-scrolling header widget above the items.
+//! - [`ListView::expanded`]: Expands the selected item inline with a detail widget.
+//! - [`ListView::expansion_frames`]: Animates the inline expansion over a number of renders.
+//! - [`ListView::expansion_easing`]: Sets the easing curve of the expansion animation.
+//! - [`ListView::min_partial_size`]: Omits the last item if only a sliver of it would be visible.
+//! - [`ListView::truncation_policy`]: Clips, hides or shrinks items that only partially fit onto the viewport.
+//! - [`ListView::main_axis_alignment`]: Centers or end-aligns the items if they do not fill the viewport.
+//! - [`ListView::size_classes`]: Maps the cross axis size to size classes for adaptive item layouts.
+//! - [`ListView::right_to_left`]: Lays out horizontal lists from the right edge.
+//! - [`ListView::selection_filter`]: Lets the application veto or skip selection changes when the list is rendered.
+//! - [`ListView::context_items`]: Always builds the given number of items around the selection.
+//! - [`ListView::placeholder`]: Renders placeholders for items that are not loaded yet.
+//! - [`ListView::build_budget`]: Limits the number of items built per render and defers the rest.
+//! - [`ListView::scroll_behavior`]: Decides which item is shown first, e.g. to keep the selection centered.
+//! - [`ListView::on_scroll_progress`]: Reports the scroll progress in cells, e.g. for minimaps.
+//! - [`ListView::editor`]: Renders an editing widget in place of the edited item.
+//! - [`ListView::dimmed`]: Dims the list except for the selected item, e.g. under a modal overlay.
+//! - [`ListView::heatmap`]: Tints the background of the items by a value.
+//! - [`ListView::selection_band`]: Highlights the selected item across the entire row, including the gutter.
+//! - [`ListView::focused`]: Switches between the focused and unfocused styles, e.g. in multi-pane applications.
+//! - [`ListView::joined_borders`]: Joins the borders of adjacent bordered items.
+//! - [`ListView::separator`]: Draws a separator between adjacent items.
+//! - [`ListView::zero_size_diagnostic`]: Renders a diagnostic if all visible items have a size of zero.
+//! - [`ListView::direction_aware_block`]: Omits the border of the block on the side of the scrollbar.
+//! - [`ListView::scrollbar`]: Renders a scrollbar whose thumb can be dragged with the mouse.
 //! - [`ListView::style`]: Defines the base style of the list.
 //! - [`ListView::block`]: Optional outer block surrounding the list.
 //!
@@ -82,6 +110,39 @@
 //! }
 //!```
 //!
+//! ## Borrowing item data
+//! The builder closure and the widgets it returns only need to live as long as
+//! the [`ListView`], i.e. `T: 'a` for a `ListView<'a, T>`. The widgets can thus
+//! borrow from data that outlives the list instead of cloning it on every
+//! render, e.g. a [`Line`](ratatui::text::Line) that borrows a `String` of the
+//! application state:
+//!
+//!```
+//! use ratatui::prelude::*;
+//! use tui_widget_list::{ListBuilder, ListState, ListView};
+//!
+//! pub struct App {
+//!     names: Vec<String>,
+//!     state: ListState,
+//! }
+//!
+//! impl Widget for &mut App {
+//!     fn render(self, area: Rect, buf: &mut Buffer) {
+//!         let names = &self.names;
+//!         let builder = ListBuilder::new(|context| {
+//!             let item = Line::from(names[context.index].as_str());
+//!             (item, 1)
+//!         });
+//!
+//!         let list = ListView::new(builder, names.len());
+//!         list.render(area, buf, &mut self.state);
+//!     }
+//! }
+//!```
+//!
+//! [`ListBuilder::from_slice`] does the indexing for you and passes each item
+//! by reference to the closure.
+//!
 //! For more examples see [tui-widget-list](https://github.com/preiter93/tui-widget-list/tree/main/examples).
 //!
 //! ## Documentation
@@ -96,14 +157,65 @@
 //! ### Infinite scrolling, scroll padding, horizontal scrolling
 //!
 //!![](examples/tapes/variants.gif?v=1)
+#[cfg(feature = "bench")]
+pub mod bench;
+pub(crate) mod cache;
+pub(crate) mod chain;
+#[cfg(feature = "demo")]
+pub mod demo;
+pub(crate) mod easing;
+pub(crate) mod export;
+pub(crate) mod heatmap;
+pub(crate) mod keymap;
+pub mod layout;
+#[cfg(feature = "legacy")]
 pub(crate) mod legacy;
+pub(crate) mod masonry;
+pub(crate) mod metadata;
+pub(crate) mod mouse;
+pub(crate) mod popup;
+#[cfg(feature = "tui-realm")]
+pub mod realm;
+pub(crate) mod recycle;
+pub(crate) mod scroll;
+pub(crate) mod snapshot;
 pub(crate) mod state;
-pub(crate) mod utils;
+pub(crate) mod sync;
+pub mod units;
 pub(crate) mod view;
+#[cfg(feature = "widgets")]
+pub(crate) mod widgets;
 
-pub use state::ListState;
-pub use view::{ListBuildContext, ListBuilder, ListView, ScrollAxis};
+pub use cache::{HashMapItemCache, ItemCache};
+pub use chain::{ChainedItem, ChainedListView};
+pub use easing::Easing;
+pub use heatmap::ColorScale;
+pub use keymap::{Key, KeyBinding, KeyChord, ListCommand, ListKeymap};
+pub use masonry::{MasonryDirection, MasonryState, MasonryView};
+pub use metadata::{ItemMetadata, RenderMetadata, ScrollbarMetadata};
+pub use mouse::{MouseOptions, ScrollWheel, ScrollbarThumbState, WheelStep};
+pub use popup::popup_area;
+pub use recycle::{RecyclePool, Recycled};
+pub use scroll::{CenteredScrollBehavior, DefaultScrollBehavior, ScrollBehavior, ScrollContext};
+pub use snapshot::{LayoutItem, LayoutSnapshot};
+pub use state::{ListState, ViewState, Wrapped};
+pub use sync::{ScrollPosition, ScrollProgress, ScrollSync};
+pub use view::{
+    ListBuildContext, ListBuilder, ListView, MainAxisAlignment, ScrollAxis, StatelessListView,
+    TruncationPolicy,
+};
+#[cfg(feature = "widgets")]
+pub use widgets::{
+    breadcrumb::BreadcrumbView,
+    container::ListItemContainer,
+    either::Either,
+    row::{RowItem, RowPriority},
+    select::{SelectMode, SelectPrompt, SelectPromptState},
+    tabs::{TabBarView, TabWidth},
+    toast::{ToastListView, ToastState},
+};
 
+#[cfg(feature = "legacy")]
 #[allow(deprecated)]
 pub use legacy::{
     traits::{PreRender, PreRenderContext},
@@ -0,0 +1,375 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+use crate::{
+    layout::Truncation,
+    view::{render_item, ListBuilder},
+    ScrollAxis,
+};
+
+/// A staggered grid of items with mixed heights, e.g. cards.
+///
+/// The items are laid out in reading order into a fixed number of columns of
+/// equal width. Each column tracks its own running height, and every item is
+/// placed into the column that is currently the shortest, so that cards of
+/// different heights pack without gaps. The grid scrolls vertically to keep
+/// the selected item visible.
+///
+/// The items are built with a [`ListBuilder`], whose closure receives the
+/// column width as the cross axis size and returns the height of the item.
+/// All items are built on every render to place them, so the grid suits
+/// moderate item counts.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+/// use tui_widget_list::{ListBuilder, MasonryDirection, MasonryState, MasonryView};
+///
+/// let builder = ListBuilder::new(|context| {
+///     let height = if context.index == 0 { 2 } else { 1 };
+///     (Line::from(format!("{}", context.index)), height)
+/// });
+/// let grid = MasonryView::new(builder, 3).columns(2);
+///
+/// let area = Rect::new(0, 0, 4, 2);
+/// let mut buf = Buffer::empty(area);
+/// let mut state = MasonryState::default();
+/// grid.render(area, &mut buf, &mut state);
+/// assert_eq!(buf, Buffer::with_lines(["0 1 ", "  2 "]));
+///
+/// state.select(Some(0));
+/// state.select_nearest(MasonryDirection::Right);
+/// assert_eq!(state.selected, Some(1));
+/// ```
+pub struct MasonryView<'a, T> {
+    /// The builder of the items.
+    builder: ListBuilder<'a, T>,
+
+    /// The number of items.
+    item_count: usize,
+
+    /// The number of columns.
+    columns: u16,
+
+    /// The base style of the grid.
+    style: Style,
+
+    /// The optional block surrounding the grid.
+    block: Option<Block<'a>>,
+}
+
+/// The state of a [`MasonryView`].
+#[derive(Debug, Clone, Default)]
+pub struct MasonryState {
+    /// The selected item. If `None`, no item is selected.
+    pub selected: Option<usize>,
+
+    /// The first visible row of the grid.
+    pub(crate) offset: usize,
+
+    /// The placement of the items during the last render.
+    pub(crate) placements: Vec<Placement>,
+}
+
+/// The position of an item in a [`MasonryView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Placement {
+    /// The column of the item.
+    pub(crate) column: u16,
+
+    /// The first row of the item.
+    pub(crate) top: usize,
+
+    /// The height of the item.
+    pub(crate) height: u16,
+}
+
+/// The direction in which [`MasonryState::select_nearest`] moves the selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MasonryDirection {
+    /// Selects the item above in the same column.
+    Up,
+
+    /// Selects the item below in the same column.
+    Down,
+
+    /// Selects the item in the column to the left that is nearest vertically.
+    Left,
+
+    /// Selects the item in the column to the right that is nearest vertically.
+    Right,
+}
+
+impl<'a, T> MasonryView<'a, T> {
+    /// Creates a new `MasonryView` with `item_count` items and two columns.
+    #[must_use]
+    pub fn new(builder: ListBuilder<'a, T>, item_count: usize) -> Self {
+        Self {
+            builder,
+            item_count,
+            columns: 2,
+            style: Style::default(),
+            block: None,
+        }
+    }
+
+    /// Sets the number of columns. Defaults to two, and at least one column
+    /// is used.
+    #[must_use]
+    pub fn columns(mut self, columns: u16) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Sets the block that surrounds the grid.
+    #[must_use]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl<T> Styled for MasonryView<'_, T> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl MasonryState {
+    /// Selects the item at `index`, or clears the selection with `None`.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Moves the selection to the nearest item in `direction`, based on the
+    /// layout of the last render. Selects the first item if none is selected,
+    /// and keeps the selection if there is no item in that direction.
+    pub fn select_nearest(&mut self, direction: MasonryDirection) {
+        let Some(selected) = self.selected else {
+            if !self.placements.is_empty() {
+                self.selected = Some(0);
+            }
+            return;
+        };
+        let Some(&current) = self.placements.get(selected) else {
+            return;
+        };
+        let center = |placement: &Placement| placement.top * 2 + usize::from(placement.height);
+        let candidates = self.placements.iter().enumerate();
+        let nearest = match direction {
+            MasonryDirection::Up => candidates
+                .filter(|(_, p)| p.column == current.column && p.top < current.top)
+                .max_by_key(|(_, p)| p.top),
+            MasonryDirection::Down => candidates
+                .filter(|(_, p)| p.column == current.column && p.top > current.top)
+                .min_by_key(|(_, p)| p.top),
+            MasonryDirection::Left | MasonryDirection::Right => {
+                let column = if direction == MasonryDirection::Left {
+                    current.column.checked_sub(1)
+                } else {
+                    current.column.checked_add(1)
+                };
+                candidates
+                    .filter(|(_, p)| Some(p.column) == column)
+                    .min_by_key(|(_, p)| center(p).abs_diff(center(&current)))
+            }
+        };
+        if let Some((index, _)) = nearest {
+            self.selected = Some(index);
+        }
+    }
+
+    /// Returns the first visible row of the grid during the last render.
+    #[must_use]
+    pub fn scroll_offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<T: Widget> StatefulWidget for MasonryView<'_, T> {
+    type State = MasonryState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let area = match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if self.item_count == 0 || area.is_empty() {
+            state.placements.clear();
+            return;
+        }
+        state.selected = state.selected.map(|index| index.min(self.item_count - 1));
+
+        // Place every item into the shortest column.
+        let column_area = |column: u16| {
+            let x = |column: u16| {
+                let offset = u32::from(area.width) * u32::from(column) / u32::from(self.columns);
+                area.x + u16::try_from(offset).unwrap_or(area.width)
+            };
+            Rect {
+                x: x(column),
+                width: x(column + 1) - x(column),
+                ..area
+            }
+        };
+        let mut heights = vec![0; usize::from(self.columns)];
+        let mut items = Vec::with_capacity(self.item_count);
+        state.placements.clear();
+        for index in 0..self.item_count {
+            let (column, top) = heights
+                .iter()
+                .copied()
+                .enumerate()
+                .min_by_key(|&(_, height)| height)
+                .unwrap_or_default();
+            let column = u16::try_from(column).unwrap_or_default();
+            let context = self.builder.context(
+                index,
+                state.selected == Some(index),
+                ScrollAxis::Vertical,
+                column_area(column).width,
+            );
+            let (widget, height) = self.builder.call_closure(&context, area.height);
+            heights[usize::from(column)] = top + usize::from(height);
+            state.placements.push(Placement {
+                column,
+                top,
+                height,
+            });
+            items.push(widget);
+        }
+
+        // Scroll such that the selected item is visible.
+        let viewport_size = usize::from(area.height);
+        if let Some(selected) = state.selected.map(|index| state.placements[index]) {
+            let bottom = selected.top + usize::from(selected.height);
+            if selected.top < state.offset {
+                state.offset = selected.top;
+            } else if bottom > state.offset + viewport_size {
+                state.offset = bottom.saturating_sub(viewport_size).min(selected.top);
+            }
+        }
+        let max_offset = heights.iter().max().copied().unwrap_or(0);
+        state.offset = state.offset.min(max_offset.saturating_sub(viewport_size));
+
+        // Render the visible items, truncating those at the edges.
+        let (offset, end) = (state.offset, state.offset + viewport_size);
+        for (widget, placement) in items.into_iter().zip(&state.placements) {
+            let bottom = placement.top + usize::from(placement.height);
+            if bottom <= offset || placement.top >= end || placement.height == 0 {
+                continue;
+            }
+            let top = placement.top.max(offset);
+            let visible = bottom.min(end) - top;
+            let item_area = Rect {
+                y: area.y + u16::try_from(top - offset).unwrap_or_default(),
+                height: u16::try_from(visible).unwrap_or_default(),
+                ..column_area(placement.column)
+            };
+            let truncation = if placement.top < offset {
+                Truncation::Top(u16::try_from(offset - placement.top).unwrap_or_default())
+            } else if bottom > end {
+                Truncation::Bot(u16::try_from(bottom - end).unwrap_or_default())
+            } else {
+                Truncation::None
+            };
+            render_item(
+                widget,
+                item_area,
+                buf,
+                placement.height,
+                &truncation,
+                self.style,
+                ScrollAxis::Vertical,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::text::Line;
+
+    use super::*;
+
+    fn grid(heights: &'static [u16]) -> MasonryView<'static, Line<'static>> {
+        let builder = ListBuilder::new(|context| {
+            let line = Line::from(format!("{}", context.index));
+            (line, heights[context.index])
+        });
+        MasonryView::new(builder, heights.len()).columns(2)
+    }
+
+    #[test]
+    fn places_items_into_the_shortest_column() {
+        // given
+        let area = Rect::new(0, 0, 4, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = MasonryState::default();
+
+        // when
+        grid(&[2, 1, 2, 1]).render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(["0 1 ", "  2 ", "3   ", "    "]));
+        let columns: Vec<_> = state.placements.iter().map(|p| p.column).collect();
+        assert_eq!(columns, vec![0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn navigates_to_the_nearest_item() {
+        // given
+        let area = Rect::new(0, 0, 4, 2);
+        let mut state = MasonryState::default();
+        let render = |state: &mut MasonryState| {
+            let mut buf = Buffer::empty(area);
+            grid(&[2, 1, 2, 1]).render(area, &mut buf, state);
+            buf
+        };
+        render(&mut state);
+
+        // when
+        state.select_nearest(MasonryDirection::Down);
+        state.select_nearest(MasonryDirection::Down);
+
+        // then
+        assert_eq!(state.selected, Some(3));
+        assert_eq!(render(&mut state), Buffer::with_lines(["  2 ", "3   "]));
+        assert_eq!(state.scroll_offset(), 1);
+
+        // when
+        state.select_nearest(MasonryDirection::Right);
+
+        // then
+        assert_eq!(state.selected, Some(2));
+
+        // when
+        state.select_nearest(MasonryDirection::Right);
+
+        // then
+        assert_eq!(state.selected, Some(2));
+
+        // when
+        state.select_nearest(MasonryDirection::Up);
+        state.select_nearest(MasonryDirection::Left);
+
+        // then
+        assert_eq!(state.selected, Some(0));
+    }
+}
@@ -0,0 +1,218 @@
+use std::{cell::Cell, ops::Range, rc::Rc};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{ScrollbarState, StatefulWidget, Widget},
+};
+
+use crate::{layout::Truncation, units, ListState, ListView, ScrollAxis, ScrollProgress};
+
+/// Everything that is known about a list after it was rendered, see
+/// [`ListView::render_with_metadata`].
+///
+/// The metadata bundles the information of several state accessors, e.g. for
+/// custom hit testing, overlays that are anchored to items, or for recording
+/// the rendered frames.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RenderMetadata {
+    /// The inner area of the list, i.e. the viewport.
+    pub area: Rect,
+
+    /// The scroll axis of the list.
+    pub scroll_axis: ScrollAxis,
+
+    /// The indices of the visible items. For infinite scrolling, the range
+    /// covers all items between the smallest and the largest visible index.
+    pub visible_range: Range<usize>,
+
+    /// The visible items in render order.
+    pub items: Vec<ItemMetadata>,
+
+    /// The geometry of a scrollbar that tracks the viewport.
+    pub scrollbar: ScrollbarMetadata,
+}
+
+/// A visible item of a [`RenderMetadata`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ItemMetadata {
+    /// The index of the item.
+    pub index: usize,
+
+    /// The visible area of the item on the buffer.
+    pub area: Rect,
+
+    /// How much of the item is cut off at the start or the end of the viewport.
+    pub truncation: Truncation,
+}
+
+/// The scrollbar geometry of a [`RenderMetadata`].
+///
+/// The thumb is measured along a track that is as long as the viewport, which
+/// is the track of a scrollbar without begin and end symbols.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ScrollbarMetadata {
+    /// The state of a [`Scrollbar`] that tracks the selected item, see
+    /// [`ListState::scrollbar_state`].
+    ///
+    /// [`Scrollbar`]: ratatui::widgets::Scrollbar
+    pub state: ScrollbarState,
+
+    /// The scroll progress of the list in cells.
+    pub progress: ScrollProgress,
+
+    /// The offset of the thumb from the start of the track.
+    pub thumb_offset: u16,
+
+    /// The size of the thumb along the track.
+    pub thumb_size: u16,
+}
+
+impl ScrollbarMetadata {
+    fn new(state: ScrollbarState, progress: ScrollProgress) -> Self {
+        let ScrollProgress {
+            offset_cells,
+            total_cells,
+            viewport_cells,
+        } = progress;
+        let track = usize::from(viewport_cells);
+        let (thumb_offset, thumb_size) = if total_cells <= track {
+            (0, track)
+        } else {
+            let thumb_size = (track * track / total_cells).max(1);
+            let scrollable = total_cells - track;
+            let thumb_offset = offset_cells.min(scrollable) * (track - thumb_size) / scrollable;
+            (thumb_offset, thumb_size)
+        };
+        Self {
+            state,
+            progress,
+            thumb_offset: units::saturating_u16(thumb_offset),
+            thumb_size: units::saturating_u16(thumb_size),
+        }
+    }
+}
+
+impl<T: Widget> ListView<'_, T> {
+    /// Renders the list like [`StatefulWidget::render`] and returns the
+    /// metadata of the rendered frame: the visible range, the areas and
+    /// truncations of the visible items, and the scrollbar geometry.
+    ///
+    /// An observer that is set with [`ListView::on_scroll_progress`] is still
+    /// notified.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line};
+    /// use tui_widget_list::{layout::Truncation, ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 3);
+    /// let mut state = ListState::default();
+    /// state.select(Some(9));
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 2));
+    /// let list = ListView::new(builder, 10);
+    /// let metadata = list.render_with_metadata(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert_eq!(metadata.visible_range, 8..10);
+    /// assert_eq!(metadata.items[0].truncation, Truncation::Top(1));
+    /// assert_eq!(metadata.items[1].area, Rect::new(0, 1, 5, 2));
+    /// assert_eq!(metadata.scrollbar.thumb_offset, 2);
+    /// ```
+    pub fn render_with_metadata(
+        mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+    ) -> RenderMetadata {
+        let progress = Rc::new(Cell::new(ScrollProgress::default()));
+        let reported = Rc::clone(&progress);
+        let mut observer = self.scroll_progress.take();
+        self.scroll_progress = Some(Box::new(move |scroll_progress| {
+            reported.set(scroll_progress);
+            if let Some(observer) = &mut observer {
+                observer(scroll_progress);
+            }
+        }));
+        let scroll_axis = self.scroll_axis;
+        self.render(area, buf, state);
+
+        let items: Vec<ItemMetadata> = state
+            .item_spans
+            .iter()
+            .map(|span| ItemMetadata {
+                index: span.index,
+                area: span.area,
+                truncation: span.truncation.clone(),
+            })
+            .collect();
+        let visible_range = match (
+            items.iter().map(|item| item.index).min(),
+            items.iter().map(|item| item.index).max(),
+        ) {
+            (Some(first), Some(last)) => first..last + 1,
+            _ => 0..0,
+        };
+        RenderMetadata {
+            area: state.inner_area,
+            scroll_axis,
+            visible_range,
+            items,
+            scrollbar: ScrollbarMetadata::new(state.scrollbar_state(), progress.get()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::text::Line;
+
+    use super::*;
+    use crate::ListBuilder;
+
+    #[test]
+    fn metadata_of_horizontal_list() {
+        // given
+        let area = Rect::new(0, 0, 7, 1);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let mut progress = Vec::new();
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 3));
+        let list = ListView::new(builder, 4)
+            .scroll_axis(ScrollAxis::Horizontal)
+            .on_scroll_progress(|p| progress.push(p));
+
+        // when
+        let metadata = list.render_with_metadata(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        assert_eq!(metadata.visible_range, 0..3);
+        assert_eq!(
+            metadata.items,
+            vec![
+                ItemMetadata {
+                    index: 0,
+                    area: Rect::new(0, 0, 3, 1),
+                    truncation: Truncation::None,
+                },
+                ItemMetadata {
+                    index: 1,
+                    area: Rect::new(3, 0, 3, 1),
+                    truncation: Truncation::None,
+                },
+                ItemMetadata {
+                    index: 2,
+                    area: Rect::new(6, 0, 1, 1),
+                    truncation: Truncation::Bot(2),
+                },
+            ]
+        );
+        assert_eq!(metadata.scrollbar.progress, progress[0]);
+        assert_eq!(
+            (
+                metadata.scrollbar.thumb_offset,
+                metadata.scrollbar.thumb_size
+            ),
+            (0, 4)
+        );
+    }
+}
@@ -0,0 +1,582 @@
+use ratatui::{
+    crossterm::event::{MouseButton, MouseEvent, MouseEventKind},
+    layout::{Position, Rect},
+};
+
+use crate::{units, ListState, ScrollAxis};
+
+/// A tick of the mouse wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollWheel {
+    /// The wheel is scrolled up.
+    Up,
+
+    /// The wheel is scrolled down.
+    Down,
+
+    /// The wheel is tilted, or a trackpad is swiped, to the left. Not all
+    /// terminals report horizontal wheel events.
+    Left,
+
+    /// The wheel is tilted, or a trackpad is swiped, to the right. Not all
+    /// terminals report horizontal wheel events.
+    Right,
+}
+
+/// The interaction state of the thumb of the built-in scrollbar, see
+/// [`ListState::scrollbar_thumb_state`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ScrollbarThumbState {
+    /// The mouse is neither over the scrollbar nor dragging it.
+    #[default]
+    Normal,
+
+    /// The mouse is over the scrollbar.
+    Hovered,
+
+    /// The thumb is being dragged.
+    Dragged,
+}
+
+/// How far the list scrolls per tick of the mouse wheel, see
+/// [`MouseOptions::wheel_step`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WheelStep {
+    /// Scrolls by the given number of items.
+    Items(u16),
+
+    /// Scrolls by the given number of rows or columns. Since the viewport
+    /// always starts at an item boundary when scrolled, the cells are rounded
+    /// to whole items using the average size of the visible items, scrolling
+    /// by at least one item.
+    Cells(u16),
+}
+
+/// Configures how a list reacts to the mouse, see
+/// [`ListState::handle_mouse_event`] and [`ListState::handle_scroll_wheel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MouseOptions {
+    /// Whether the vertical wheel scrolls horizontal lists, since most mice
+    /// lack a horizontal wheel. Scrolling down scrolls towards the end of the
+    /// list. Defaults to true.
+    pub vertical_wheel_scrolls_horizontally: bool,
+
+    /// Whether a click with the left mouse button selects the item under the
+    /// cursor. Defaults to true.
+    pub click_selects: bool,
+
+    /// Whether the thumb of the built-in scrollbar can be dragged, see
+    /// [`ListView::scrollbar`]. Defaults to true.
+    ///
+    /// [`ListView::scrollbar`]: crate::ListView::scrollbar
+    pub scrollbar_dragging: bool,
+
+    /// How far the list scrolls per tick of the mouse wheel. Defaults to one
+    /// item.
+    pub wheel_step: WheelStep,
+
+    /// Whether the mouse wheel moves the selection instead of the viewport,
+    /// like [`ListState::next`] and [`ListState::previous`]. Defaults to false.
+    pub wheel_moves_selection: bool,
+}
+
+impl Default for MouseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MouseOptions {
+    /// Creates the default mouse options.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            vertical_wheel_scrolls_horizontally: true,
+            click_selects: true,
+            scrollbar_dragging: true,
+            wheel_step: WheelStep::Items(1),
+            wheel_moves_selection: false,
+        }
+    }
+
+    /// Sets whether the vertical wheel scrolls horizontal lists.
+    #[must_use]
+    pub const fn vertical_wheel_scrolls_horizontally(mut self, enabled: bool) -> Self {
+        self.vertical_wheel_scrolls_horizontally = enabled;
+        self
+    }
+
+    /// Sets whether a click selects the item under the cursor.
+    #[must_use]
+    pub const fn click_selects(mut self, enabled: bool) -> Self {
+        self.click_selects = enabled;
+        self
+    }
+
+    /// Sets whether the thumb of the built-in scrollbar can be dragged.
+    #[must_use]
+    pub const fn scrollbar_dragging(mut self, enabled: bool) -> Self {
+        self.scrollbar_dragging = enabled;
+        self
+    }
+
+    /// Sets how far the list scrolls per tick of the mouse wheel.
+    #[must_use]
+    pub const fn wheel_step(mut self, wheel_step: WheelStep) -> Self {
+        self.wheel_step = wheel_step;
+        self
+    }
+
+    /// Sets whether the mouse wheel moves the selection instead of the viewport.
+    #[must_use]
+    pub const fn wheel_moves_selection(mut self, enabled: bool) -> Self {
+        self.wheel_moves_selection = enabled;
+        self
+    }
+}
+
+impl ListState {
+    /// Handles a mouse event of the crossterm backend. A click with the left
+    /// mouse button selects the item under the cursor, and the mouse wheel
+    /// scrolls the viewport, see [`ListState::handle_scroll_wheel`]. Pressing
+    /// the left mouse button on the built-in scrollbar and dragging it selects
+    /// the item that corresponds to the position along the track.
+    ///
+    /// The items are located by their areas during the last render, which
+    /// includes partially visible items at the edges of the viewport.
+    ///
+    /// Mouse movements update whether the mouse hovers over the scrollbar, see
+    /// [`ListState::scrollbar_thumb_state`].
+    ///
+    /// Returns true if the event was handled, i.e. if an item was selected,
+    /// the viewport was scrolled, the scrollbar was dragged or started or
+    /// stopped being hovered.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+    ///     layout::Rect,
+    ///     text::Line,
+    ///     widgets::StatefulWidget,
+    /// };
+    /// use tui_widget_list::{ListBuilder, ListState, ListView, MouseOptions};
+    ///
+    /// let area = Rect::new(0, 0, 5, 5);
+    /// let mut state = ListState::default();
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// let click = MouseEvent {
+    ///     kind: MouseEventKind::Down(MouseButton::Left),
+    ///     column: 2,
+    ///     row: 3,
+    ///     modifiers: KeyModifiers::NONE,
+    /// };
+    /// assert!(state.handle_mouse_event(click, &MouseOptions::default()));
+    /// assert_eq!(state.selected, Some(3));
+    /// ```
+    pub fn handle_mouse_event(&mut self, event: MouseEvent, options: &MouseOptions) -> bool {
+        let position = Position::new(event.column, event.row);
+        let wheel = match event.kind {
+            MouseEventKind::Down(MouseButton::Left)
+                if options.scrollbar_dragging && self.scrollbar_area.contains(position) =>
+            {
+                self.scrollbar_drag = true;
+                self.drag_scrollbar_to(position);
+                return true;
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.scrollbar_drag => {
+                self.drag_scrollbar_to(position);
+                return true;
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.scrollbar_drag => {
+                self.scrollbar_drag = false;
+                self.scrollbar_hovered = self.scrollbar_area.contains(position);
+                return true;
+            }
+            MouseEventKind::Moved => {
+                let hovered = self.scrollbar_area.contains(position);
+                let changed = hovered != self.scrollbar_hovered;
+                self.scrollbar_hovered = hovered;
+                return changed;
+            }
+            MouseEventKind::Down(MouseButton::Left) if options.click_selects => {
+                if self.editing.is_some() {
+                    return false;
+                }
+                let Some(index) = self.item_at_position(position) else {
+                    return false;
+                };
+                self.select(Some(index));
+                return true;
+            }
+            MouseEventKind::ScrollUp => ScrollWheel::Up,
+            MouseEventKind::ScrollDown => ScrollWheel::Down,
+            MouseEventKind::ScrollLeft => ScrollWheel::Left,
+            MouseEventKind::ScrollRight => ScrollWheel::Right,
+            _ => return false,
+        };
+        self.handle_scroll_wheel(wheel, options)
+    }
+
+    /// Returns the interaction state of the thumb of the built-in scrollbar,
+    /// which is tracked by [`ListState::handle_mouse_event`]. The thumb is
+    /// styled accordingly, see [`ListView::scrollbar_thumb_style`].
+    ///
+    /// [`ListView::scrollbar_thumb_style`]: crate::ListView::scrollbar_thumb_style
+    #[must_use]
+    pub fn scrollbar_thumb_state(&self) -> ScrollbarThumbState {
+        if self.scrollbar_drag {
+            ScrollbarThumbState::Dragged
+        } else if self.scrollbar_hovered {
+            ScrollbarThumbState::Hovered
+        } else {
+            ScrollbarThumbState::Normal
+        }
+    }
+
+    /// Selects the item that corresponds to `position` along the track of the
+    /// built-in scrollbar, such that the viewport jumps to it. Positions beyond
+    /// the ends of the track select the first or the last item. The track is
+    /// mirrored if the list starts from the right edge.
+    fn drag_scrollbar_to(&mut self, position: Position) {
+        if self.num_elements == 0 {
+            return;
+        }
+        let track = self.scrollbar_area;
+        let position = Rect::new(position.x, position.y, 0, 0);
+        let track_size = usize::from(self.scroll_axis.main_size(track));
+        let track_pos = self.scroll_axis.main_pos(track);
+        let offset = usize::from(
+            self.scroll_axis
+                .main_pos(position)
+                .saturating_sub(track_pos),
+        )
+        .min(track_size.saturating_sub(1));
+        let last = self.num_elements - 1;
+        let index = match track_size {
+            0 | 1 => 0,
+            _ => (offset * last + (track_size - 1) / 2) / (track_size - 1),
+        };
+        let index = if self.scrollbar_mirrored {
+            last - index
+        } else {
+            index
+        };
+        self.select(Some(index));
+    }
+
+    /// Handles a tick of the mouse wheel by scrolling the viewport along the
+    /// scroll axis of the last render, see [`ListState::scroll_by`], or by
+    /// moving the selection if configured in `options`.
+    ///
+    /// Vertical lists scroll with the vertical wheel. Horizontal lists scroll
+    /// with the horizontal wheel, and with the vertical wheel unless disabled
+    /// in `options`.
+    ///
+    /// Returns true if the viewport was scrolled or the selection was moved.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView, MouseOptions, ScrollAxis, ScrollWheel};
+    ///
+    /// let area = Rect::new(0, 0, 3, 1);
+    /// let mut state = ListState::default();
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let list = ListView::new(builder, 10).scroll_axis(ScrollAxis::Horizontal);
+    /// list.render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert!(state.handle_scroll_wheel(ScrollWheel::Down, &MouseOptions::default()));
+    /// assert_eq!(state.scroll_offset_index(), 1);
+    /// ```
+    pub fn handle_scroll_wheel(&mut self, wheel: ScrollWheel, options: &MouseOptions) -> bool {
+        let is_vertical_wheel = matches!(wheel, ScrollWheel::Up | ScrollWheel::Down);
+        let scrolls = match self.scroll_axis {
+            ScrollAxis::Vertical => is_vertical_wheel,
+            ScrollAxis::Horizontal => {
+                !is_vertical_wheel || options.vertical_wheel_scrolls_horizontally
+            }
+        };
+        if !scrolls {
+            return false;
+        }
+        let forward = matches!(wheel, ScrollWheel::Down | ScrollWheel::Right);
+        let items = self.wheel_step_items(options.wheel_step);
+
+        if options.wheel_moves_selection {
+            let selected = self.selected;
+            for _ in 0..items {
+                if forward {
+                    self.next();
+                } else {
+                    self.previous();
+                }
+            }
+            return self.selected != selected;
+        }
+
+        let delta = isize::try_from(items).unwrap_or(isize::MAX);
+        let offset = self.view_state.offset;
+        self.scroll_by(if forward { delta } else { -delta });
+        self.view_state.offset != offset
+    }
+
+    /// Returns the number of items to scroll per tick of the mouse wheel.
+    fn wheel_step_items(&self, wheel_step: WheelStep) -> u16 {
+        match wheel_step {
+            WheelStep::Items(items) => items,
+            WheelStep::Cells(cells) => {
+                let visible_cells: u16 = self.item_spans.iter().map(|span| span.size).sum();
+                let visible_items = units::saturating_u16(self.item_spans.len());
+                let average_size = visible_cells.checked_div(visible_items).unwrap_or(1).max(1);
+                ((cells + average_size / 2) / average_size).max(1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        buffer::Buffer,
+        crossterm::event::KeyModifiers,
+        layout::Rect,
+        style::{Color, Style},
+        text::Line,
+        widgets::StatefulWidget,
+    };
+
+    use super::*;
+    use crate::{ListBuilder, ListView};
+
+    fn render(state: &mut ListState, scroll_axis: ScrollAxis) {
+        let area = Rect::new(0, 0, 2, 2);
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+        let list = ListView::new(builder, 10).scroll_axis(scroll_axis);
+        list.render(area, &mut Buffer::empty(area), state);
+    }
+
+    fn mouse_event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn click_selects_item() {
+        // given
+        let mut state = ListState::default();
+        let area = Rect::new(0, 0, 4, 3);
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 2));
+        ListView::new(builder, 10)
+            .scroll_axis(ScrollAxis::Horizontal)
+            .right_to_left(true)
+            .render(area, &mut Buffer::empty(area), &mut state);
+        let click = MouseEventKind::Down(MouseButton::Left);
+
+        // when
+        let handled = [
+            state.handle_mouse_event(mouse_event(click, 0, 2), &MouseOptions::default()),
+            state.handle_mouse_event(mouse_event(click, 9, 0), &MouseOptions::default()),
+            state.handle_mouse_event(
+                mouse_event(click, 3, 0),
+                &MouseOptions::new().click_selects(false),
+            ),
+        ];
+
+        // then
+        assert_eq!(handled, [true, false, false]);
+        assert_eq!(state.selected, Some(1));
+    }
+
+    #[test]
+    fn drag_scrollbar_thumb() {
+        // given
+        let mut state = ListState::default();
+        let area = Rect::new(0, 0, 3, 5);
+        let render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            let list = ListView::new(builder, 9).scrollbar(true);
+            list.render(area, &mut Buffer::empty(area), state);
+        };
+        render(&mut state);
+        let options = MouseOptions::default();
+
+        // when
+        let handled = [
+            state.handle_mouse_event(
+                mouse_event(MouseEventKind::Down(MouseButton::Left), 2, 2),
+                &options,
+            ),
+            state.handle_mouse_event(
+                mouse_event(MouseEventKind::Drag(MouseButton::Left), 0, 9),
+                &options,
+            ),
+            state.handle_mouse_event(
+                mouse_event(MouseEventKind::Up(MouseButton::Left), 0, 9),
+                &options,
+            ),
+            state.handle_mouse_event(
+                mouse_event(MouseEventKind::Drag(MouseButton::Left), 2, 0),
+                &options,
+            ),
+        ];
+        render(&mut state);
+
+        // then
+        assert_eq!(handled, [true, true, true, false]);
+        assert_eq!(state.selected, Some(8));
+        assert_eq!(state.scroll_offset_index(), 4);
+    }
+
+    #[test]
+    fn drag_scrollbar_thumb_right_to_left() {
+        // given
+        let mut state = ListState::default();
+        let area = Rect::new(0, 0, 5, 2);
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 9)
+                .scroll_axis(ScrollAxis::Horizontal)
+                .right_to_left(true)
+                .scrollbar(true)
+                .render(area, &mut buf, state);
+            buf
+        };
+        state.select(Some(0));
+        let buf = render(&mut state);
+        let options = MouseOptions::default();
+
+        // then
+        assert_eq!(buf[(4, 1)].symbol(), "█");
+        assert_eq!(buf[(0, 1)].symbol(), "═");
+
+        // when
+        let handled = [
+            state.handle_mouse_event(
+                mouse_event(MouseEventKind::Down(MouseButton::Left), 4, 1),
+                &options,
+            ),
+            state.handle_mouse_event(
+                mouse_event(MouseEventKind::Drag(MouseButton::Left), 0, 1),
+                &options,
+            ),
+        ];
+        let buf = render(&mut state);
+
+        // then
+        assert_eq!(handled, [true, true]);
+        assert_eq!(state.selected, Some(8));
+        assert_eq!(buf[(0, 1)].symbol(), "█");
+        assert_eq!(buf[(0, 0)].symbol(), "8");
+    }
+
+    #[test]
+    fn scrollbar_thumb_state() {
+        // given
+        let mut state = ListState::default();
+        let area = Rect::new(0, 0, 3, 5);
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 10)
+                .scrollbar(true)
+                .scrollbar_thumb_style(Style::new().fg(Color::Gray))
+                .scrollbar_thumb_hover_style(Style::new().fg(Color::White))
+                .scrollbar_thumb_drag_style(Style::new().fg(Color::Yellow))
+                .render(area, &mut buf, state);
+            buf.cell((2, 0)).map(|cell| cell.fg)
+        };
+        let options = MouseOptions::default();
+        let normal = render(&mut state);
+
+        // when
+        state.handle_mouse_event(mouse_event(MouseEventKind::Moved, 2, 1), &options);
+        let hovered = (state.scrollbar_thumb_state(), render(&mut state));
+        state.handle_mouse_event(
+            mouse_event(MouseEventKind::Down(MouseButton::Left), 2, 0),
+            &options,
+        );
+        let dragged = (state.scrollbar_thumb_state(), render(&mut state));
+        state.handle_mouse_event(
+            mouse_event(MouseEventKind::Up(MouseButton::Left), 0, 0),
+            &options,
+        );
+
+        // then
+        assert_eq!(normal, Some(Color::Gray));
+        assert_eq!(hovered, (ScrollbarThumbState::Hovered, Some(Color::White)));
+        assert_eq!(dragged, (ScrollbarThumbState::Dragged, Some(Color::Yellow)));
+        assert_eq!(state.scrollbar_thumb_state(), ScrollbarThumbState::Normal);
+    }
+
+    #[test]
+    fn horizontal_wheel_mapping() {
+        // given
+        let mut state = ListState::default();
+        render(&mut state, ScrollAxis::Horizontal);
+        let disabled = MouseOptions::new().vertical_wheel_scrolls_horizontally(false);
+
+        // when
+        let scrolled = [
+            state.handle_scroll_wheel(ScrollWheel::Down, &MouseOptions::default()),
+            state.handle_scroll_wheel(ScrollWheel::Right, &MouseOptions::default()),
+            state.handle_scroll_wheel(ScrollWheel::Down, &disabled),
+            state.handle_scroll_wheel(ScrollWheel::Left, &disabled),
+        ];
+
+        // then
+        assert_eq!(scrolled, [true, true, false, true]);
+        assert_eq!(state.scroll_offset_index(), 1);
+    }
+
+    #[test]
+    fn wheel_step() {
+        // given
+        let area = Rect::new(0, 0, 1, 6);
+        let mut state = ListState::default();
+        state.select(Some(0));
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 2));
+        ListView::new(builder, 20).render(area, &mut Buffer::empty(area), &mut state);
+        let cells = MouseOptions::new().wheel_step(WheelStep::Cells(5));
+        let selection = MouseOptions::new()
+            .wheel_step(WheelStep::Items(2))
+            .wheel_moves_selection(true);
+
+        // when
+        state.handle_scroll_wheel(ScrollWheel::Down, &cells);
+        let offset = state.scroll_offset_index();
+        state.handle_scroll_wheel(ScrollWheel::Down, &selection);
+
+        // then
+        assert_eq!(offset, 3);
+        assert_eq!(state.scroll_offset_index(), 3);
+        assert_eq!(state.selected, Some(5));
+    }
+
+    #[test]
+    fn vertical_wheel_mapping() {
+        // given
+        let mut state = ListState::default();
+        render(&mut state, ScrollAxis::Vertical);
+
+        // when
+        let scrolled = [
+            state.handle_scroll_wheel(ScrollWheel::Right, &MouseOptions::default()),
+            state.handle_scroll_wheel(ScrollWheel::Down, &MouseOptions::default()),
+            state.handle_scroll_wheel(ScrollWheel::Up, &MouseOptions::default()),
+            state.handle_scroll_wheel(ScrollWheel::Up, &MouseOptions::default()),
+        ];
+
+        // then
+        assert_eq!(scrolled, [false, true, true, false]);
+        assert_eq!(state.scroll_offset_index(), 0);
+    }
+}
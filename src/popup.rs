@@ -0,0 +1,119 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    widgets::{Clear, StatefulWidget, Widget},
+};
+
+use crate::{ListState, ListView};
+
+/// Computes the area of a popup of the given `size` that is anchored to `anchor`,
+/// e.g. an autocomplete dropdown below an input field.
+///
+/// The popup is placed below the anchor, or above it if there is not enough space
+/// below and more space above. The popup is aligned to the left edge of the anchor,
+/// shifted to the left if it would exceed the `bounds`, and shrunk to fit into the
+/// `bounds`, which are usually the area of the frame.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::{Rect, Size};
+/// use tui_widget_list::popup_area;
+///
+/// let bounds = Rect::new(0, 0, 20, 10);
+///
+/// // Enough space below the anchor
+/// let anchor = Rect::new(2, 1, 10, 1);
+/// assert_eq!(popup_area(anchor, bounds, Size::new(8, 4)), Rect::new(2, 2, 8, 4));
+///
+/// // Flips above the anchor if there is not enough space below
+/// let anchor = Rect::new(2, 8, 10, 1);
+/// assert_eq!(popup_area(anchor, bounds, Size::new(8, 4)), Rect::new(2, 4, 8, 4));
+/// ```
+#[must_use]
+pub fn popup_area(anchor: Rect, bounds: Rect, size: Size) -> Rect {
+    let anchor = anchor.clamp(bounds);
+
+    // Align to the anchor and shift to the left if the popup exceeds the bounds
+    let width = size.width.min(bounds.width);
+    let x = anchor.x.min(bounds.right() - width);
+
+    // Place the popup below the anchor, or above if there is more space
+    let space_below = bounds.bottom() - anchor.bottom();
+    let space_above = anchor.top() - bounds.top();
+    let (y, height) = if space_below >= size.height || space_below >= space_above {
+        (anchor.bottom(), size.height.min(space_below))
+    } else {
+        let height = size.height.min(space_above);
+        (anchor.top() - height, height)
+    };
+
+    Rect::new(x, y, width, height)
+}
+
+impl<T: Widget> ListView<'_, T> {
+    /// Renders the list as a popup anchored to `anchor`, e.g. as an autocomplete
+    /// dropdown. The popup area is computed with [`popup_area`] and cleared before
+    /// the list is rendered into it.
+    ///
+    /// Returns the area of the popup.
+    pub fn render_popup(
+        self,
+        anchor: Rect,
+        bounds: Rect,
+        size: Size,
+        buf: &mut Buffer,
+        state: &mut ListState,
+    ) -> Rect {
+        let area = popup_area(anchor, bounds, size);
+        Clear.render(area, buf);
+        self.render(area, buf, state);
+        area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::text::Line;
+
+    use super::*;
+    use crate::ListBuilder;
+
+    #[test]
+    fn shift_left_at_right_edge() {
+        let bounds = Rect::new(0, 0, 20, 10);
+        let anchor = Rect::new(15, 0, 5, 1);
+        let area = popup_area(anchor, bounds, Size::new(8, 3));
+        assert_eq!(area, Rect::new(12, 1, 8, 3));
+    }
+
+    #[test]
+    fn shrink_to_larger_side() {
+        let bounds = Rect::new(0, 0, 20, 10);
+        let anchor = Rect::new(0, 6, 5, 1);
+        let area = popup_area(anchor, bounds, Size::new(30, 8));
+        assert_eq!(area, Rect::new(0, 0, 20, 6));
+    }
+
+    #[test]
+    fn render_popup_clears_area() {
+        // given
+        let bounds = Rect::new(0, 0, 6, 3);
+        let mut buf = Buffer::with_lines(vec!["xxxxxx", "xxxxxx", "xxxxxx"]);
+        let builder = ListBuilder::new(|_| (Line::from("ab"), 1));
+        let list = ListView::new(builder, 1);
+
+        // when
+        let area = popup_area(Rect::new(1, 0, 2, 1), bounds, Size::new(3, 2));
+        let rendered = list.render_popup(
+            Rect::new(1, 0, 2, 1),
+            bounds,
+            Size::new(3, 2),
+            &mut buf,
+            &mut ListState::default(),
+        );
+
+        // then
+        assert_eq!(area, rendered);
+        assert_eq!(buf, Buffer::with_lines(vec!["xxxxxx", "xab xx", "x   xx"]));
+    }
+}
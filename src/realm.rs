@@ -0,0 +1,354 @@
+//! An adapter for the [tui-realm](https://github.com/veeso/tui-realm) framework.
+//!
+//! [`ListComponent`] implements [`MockComponent`] around a [`ListView`] and
+//! the [`ListState`] it owns. It is configured with attributes and driven by
+//! commands, so an application only has to wrap it into a [`Component`] that
+//! translates its events into commands and the command results into messages.
+//!
+//! The following attributes are supported:
+//! - [`Attribute::Value`]: The number of items as [`AttrValue::Length`].
+//! - [`Attribute::Borders`]: The borders of the surrounding block.
+//! - [`Attribute::Title`]: The title of the surrounding block.
+//! - [`Attribute::Foreground`] and [`Attribute::Background`]: The colors of the list.
+//! - [`Attribute::Style`]: The base style of the list.
+//! - [`Attribute::Direction`]: The scroll axis. [`props::Direction::Left`] and
+//!   [`props::Direction::Right`] scroll horizontally. Defaults to vertical scrolling.
+//! - [`Attribute::Rewind`]: Whether the selection wraps around at the ends of
+//!   the list. Defaults to `true`.
+//! - [`Attribute::Focus`]: Whether the list is focused, see [`ListView::focused`].
+//! - [`Attribute::Display`]: Whether the list is rendered.
+//!
+//! The state of the component is the index of the selected item as
+//! [`StateValue::Usize`], or [`State::None`] if no item is selected.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::text::Line;
+//! use tui_widget_list::realm::ListComponent;
+//! use tuirealm::{
+//!     command::{Cmd, CmdResult, Direction},
+//!     event::{Key, KeyEvent},
+//!     Component, Event, MockComponent, NoUserEvent, State, StateValue,
+//! };
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum Msg {
+//!     Open(usize),
+//! }
+//!
+//! #[derive(MockComponent)]
+//! struct Files {
+//!     component: ListComponent<'static, Line<'static>>,
+//! }
+//!
+//! impl Component<Msg, NoUserEvent> for Files {
+//!     fn on(&mut self, event: Event<NoUserEvent>) -> Option<Msg> {
+//!         let Event::Keyboard(KeyEvent { code, .. }) = event else {
+//!             return None;
+//!         };
+//!         let cmd = match code {
+//!             Key::Down => Cmd::Move(Direction::Down),
+//!             Key::Up => Cmd::Move(Direction::Up),
+//!             Key::Enter => Cmd::Submit,
+//!             _ => return None,
+//!         };
+//!         match self.perform(cmd) {
+//!             CmdResult::Submit(State::One(StateValue::Usize(index))) => Some(Msg::Open(index)),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! let component = ListComponent::new(
+//!     |context| (Line::from(format!("file_{}.txt", context.index)), 1),
+//!     10,
+//! );
+//! let mut files = Files { component };
+//!
+//! files.on(Event::Keyboard(KeyEvent::from(Key::Down)));
+//! files.on(Event::Keyboard(KeyEvent::from(Key::Down)));
+//! let msg = files.on(Event::Keyboard(KeyEvent::from(Key::Enter)));
+//! assert_eq!(msg, Some(Msg::Open(1)));
+//! ```
+//!
+//! [`Component`]: tuirealm::Component
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Widget},
+};
+use tuirealm::{
+    command::{Cmd, CmdResult, Direction, Position},
+    props::{self, AttrValue, Attribute, Props},
+    Frame, MockComponent, State, StateValue,
+};
+
+use crate::{ListBuildContext, ListBuilder, ListCommand, ListState, ListView, ScrollAxis};
+
+/// A type alias for the closure that builds the items.
+type ItemClosure<'a, T> = dyn Fn(&ListBuildContext) -> (T, u16) + 'a;
+
+/// A tui-realm component that renders a [`ListView`] and owns its [`ListState`].
+///
+/// The items are built with a closure like the one of [`ListBuilder::new`].
+/// See the [module documentation](self) for the supported attributes and
+/// commands.
+pub struct ListComponent<'a, T> {
+    /// The attributes of the component.
+    props: Props,
+
+    /// The state of the list.
+    state: ListState,
+
+    /// Builds the items of the list.
+    closure: Box<ItemClosure<'a, T>>,
+}
+
+impl<'a, T: Widget> ListComponent<'a, T> {
+    /// Creates a new `ListComponent` with `item_count` items that are built
+    /// with `closure`.
+    #[must_use]
+    pub fn new<F>(closure: F, item_count: usize) -> Self
+    where
+        F: Fn(&ListBuildContext) -> (T, u16) + 'a,
+    {
+        let mut props = Props::default();
+        props.set(Attribute::Value, AttrValue::Length(item_count));
+        Self {
+            props,
+            state: ListState::default(),
+            closure: Box::new(closure),
+        }
+    }
+
+    /// Returns the state of the list, e.g. to read the viewport position.
+    #[must_use]
+    pub fn list_state(&self) -> &ListState {
+        &self.state
+    }
+
+    /// Returns the state of the list mutably, e.g. to mark items.
+    pub fn list_state_mut(&mut self) -> &mut ListState {
+        &mut self.state
+    }
+
+    /// Returns the number of items, see [`Attribute::Value`].
+    fn item_count(&self) -> usize {
+        self.props
+            .get_ref(Attribute::Value)
+            .and_then(AttrValue::as_length)
+            .unwrap_or(0)
+    }
+
+    /// Returns the value of a flag attribute, or `default` if it is not set.
+    fn flag(&self, attr: Attribute, default: bool) -> bool {
+        self.props
+            .get_ref(attr)
+            .and_then(AttrValue::as_flag)
+            .unwrap_or(default)
+    }
+
+    /// Returns the scroll axis, see [`Attribute::Direction`].
+    fn scroll_axis(&self) -> ScrollAxis {
+        match self
+            .props
+            .get_ref(Attribute::Direction)
+            .and_then(AttrValue::as_direction)
+        {
+            Some(props::Direction::Left | props::Direction::Right) => ScrollAxis::Horizontal,
+            _ => ScrollAxis::Vertical,
+        }
+    }
+
+    /// Returns the surrounding block, if borders or a title are set.
+    fn block(&self) -> Option<Block<'static>> {
+        let borders = self
+            .props
+            .get_ref(Attribute::Borders)
+            .and_then(AttrValue::as_borders);
+        let title = self
+            .props
+            .get_ref(Attribute::Title)
+            .and_then(AttrValue::as_title);
+        if borders.is_none() && title.is_none() {
+            return None;
+        }
+        let mut block = Block::new();
+        if let Some(borders) = borders {
+            block = block
+                .borders(borders.sides)
+                .border_type(borders.modifiers)
+                .border_style(borders.style());
+        }
+        if let Some((title, alignment)) = title {
+            block = block.title(title.clone()).title_alignment(*alignment);
+        }
+        Some(block)
+    }
+
+    /// Returns the base style, see [`Attribute::Style`].
+    fn style(&self) -> Style {
+        let mut style = self
+            .props
+            .get_ref(Attribute::Style)
+            .and_then(AttrValue::as_style)
+            .unwrap_or_default();
+        if let Some(color) = self
+            .props
+            .get_ref(Attribute::Foreground)
+            .and_then(AttrValue::as_color)
+        {
+            style = style.fg(color);
+        }
+        if let Some(color) = self
+            .props
+            .get_ref(Attribute::Background)
+            .and_then(AttrValue::as_color)
+        {
+            style = style.bg(color);
+        }
+        style
+    }
+
+    /// Executes a navigation command and reports whether the selection changed.
+    fn execute(&mut self, command: ListCommand) -> CmdResult {
+        let selected = self.state.selected;
+        self.state.set_num_elements(self.item_count());
+        self.state.infinite_scrolling = self.flag(Attribute::Rewind, true);
+        self.state.execute(command);
+        self.changed(selected)
+    }
+
+    /// Returns [`CmdResult::Changed`] if the selection differs from `selected`.
+    fn changed(&self, selected: Option<usize>) -> CmdResult {
+        if self.state.selected == selected {
+            CmdResult::None
+        } else {
+            CmdResult::Changed(self.state())
+        }
+    }
+}
+
+impl<T: Widget> MockComponent for ListComponent<'_, T> {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.flag(Attribute::Display, true) {
+            return;
+        }
+        let closure = &self.closure;
+        let builder = ListBuilder::new(|context| closure(context));
+        let mut list = ListView::new(builder, self.item_count())
+            .scroll_axis(self.scroll_axis())
+            .infinite_scrolling(self.flag(Attribute::Rewind, true))
+            .focused(self.flag(Attribute::Focus, true))
+            .style(self.style());
+        if let Some(block) = self.block() {
+            list = list.block(block);
+        }
+        frame.render_stateful_widget(list, area, &mut self.state);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        match self.state.selected {
+            Some(index) => State::One(StateValue::Usize(index)),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Move(Direction::Down | Direction::Right) => self.execute(ListCommand::Next),
+            Cmd::Move(Direction::Up | Direction::Left) => self.execute(ListCommand::Previous),
+            Cmd::Scroll(Direction::Down | Direction::Right) => self.execute(ListCommand::NextPage),
+            Cmd::Scroll(Direction::Up | Direction::Left) => self.execute(ListCommand::PreviousPage),
+            Cmd::GoTo(Position::Begin) => self.execute(ListCommand::First),
+            Cmd::GoTo(Position::End) => self.execute(ListCommand::Last),
+            Cmd::GoTo(Position::At(index)) => {
+                let selected = self.state.selected;
+                let item_count = self.item_count();
+                self.state
+                    .select((index < item_count).then_some(index).or(selected));
+                self.changed(selected)
+            }
+            Cmd::Submit => CmdResult::Submit(self.state()),
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, buffer::Buffer, text::Line, Terminal};
+    use tuirealm::props::Borders;
+
+    use super::*;
+
+    fn component() -> ListComponent<'static, Line<'static>> {
+        ListComponent::new(|context| (Line::from(format!("{}", context.index)), 1), 5)
+    }
+
+    #[test]
+    fn perform_commands() {
+        // given
+        let mut component = component();
+        component.attr(Attribute::Rewind, AttrValue::Flag(false));
+
+        // when
+        let result = component.perform(Cmd::Move(Direction::Down));
+
+        // then
+        assert_eq!(result, CmdResult::Changed(State::One(StateValue::Usize(0))));
+
+        // when
+        let result = component.perform(Cmd::GoTo(Position::End));
+
+        // then
+        assert_eq!(result, CmdResult::Changed(State::One(StateValue::Usize(4))));
+
+        // when
+        let result = component.perform(Cmd::Move(Direction::Down));
+
+        // then
+        assert_eq!(result, CmdResult::None);
+
+        // when
+        let result = component.perform(Cmd::GoTo(Position::At(2)));
+
+        // then
+        assert_eq!(result, CmdResult::Changed(State::One(StateValue::Usize(2))));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(2)))
+        );
+    }
+
+    #[test]
+    fn view_with_attributes() {
+        // given
+        let mut terminal = Terminal::new(TestBackend::new(5, 4)).unwrap();
+        let mut component = component();
+        component.attr(Attribute::Borders, AttrValue::Borders(Borders::default()));
+        component.attr(Attribute::Value, AttrValue::Length(3));
+
+        // when
+        component.perform(Cmd::GoTo(Position::End));
+        terminal
+            .draw(|frame| component.view(frame, frame.area()))
+            .unwrap();
+
+        // then
+        assert_eq!(
+            terminal.backend().buffer(),
+            &Buffer::with_lines(["┌───┐", "│1  │", "│2  │", "└───┘"])
+        );
+        assert_eq!(component.list_state().scroll_offset_index(), 1);
+    }
+}
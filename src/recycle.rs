@@ -0,0 +1,202 @@
+use std::cell::RefCell;
+
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// A pool of item widgets that are handed back by the list once they leave
+/// the viewport, such that the builder can reuse their allocations, e.g. the
+/// buffers of pre-rendered canvases, for incoming indices.
+///
+/// The builder takes an idle widget from the pool with [`RecyclePool::take`],
+/// resets it for the index to build and wraps it with [`RecyclePool::recycle`].
+/// The list renders the wrapped widget by reference. Afterwards, or when the
+/// list drops the widget without rendering it, e.g. because it did not fit
+/// onto the viewport, the widget returns to the pool.
+///
+/// The pool adapts its size to the demand: it keeps at most as many idle
+/// widgets as have been in use at the same time, so it does not grow beyond
+/// the number of items that a list builds per render.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::{StatefulWidget, Widget}};
+/// use tui_widget_list::{ListBuilder, ListState, ListView, RecyclePool};
+///
+/// #[derive(Default)]
+/// struct Canvas {
+///     cells: Vec<char>,
+/// }
+///
+/// impl Widget for &Canvas {
+///     fn render(self, area: Rect, buf: &mut Buffer) {
+///         Line::from(self.cells.iter().collect::<String>()).render(area, buf);
+///     }
+/// }
+///
+/// let pool = RecyclePool::new();
+/// let mut state = ListState::default();
+/// let area = Rect::new(0, 0, 5, 3);
+/// for _ in 0..2 {
+///     let builder = ListBuilder::new(|context| {
+///         let mut canvas: Canvas = pool.take().unwrap_or_default();
+///         canvas.cells.clear();
+///         canvas.cells.extend(context.index.to_string().chars());
+///         (pool.recycle(canvas), 1)
+///     });
+///     ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+/// }
+/// assert_eq!(pool.idle(), 3);
+/// ```
+#[derive(Debug)]
+pub struct RecyclePool<W> {
+    inner: RefCell<Pool<W>>,
+}
+
+/// The mutable part of a [`RecyclePool`].
+#[derive(Debug)]
+struct Pool<W> {
+    /// The widgets that are ready to be reused.
+    idle: Vec<W>,
+
+    /// The number of wrapped widgets that are currently in use.
+    in_use: usize,
+
+    /// The highest number of wrapped widgets that were in use at the same time.
+    peak: usize,
+}
+
+impl<W> Default for RecyclePool<W> {
+    fn default() -> Self {
+        Self {
+            inner: RefCell::new(Pool {
+                idle: Vec::new(),
+                in_use: 0,
+                peak: 0,
+            }),
+        }
+    }
+}
+
+impl<W> RecyclePool<W> {
+    /// Creates an empty pool.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes an idle widget from the pool, if any. The widget still holds the
+    /// content of the item it was built for and must be reset by the caller.
+    #[must_use]
+    pub fn take(&self) -> Option<W> {
+        self.inner.borrow_mut().idle.pop()
+    }
+
+    /// Wraps a widget such that it returns to the pool once the list is done
+    /// with it.
+    #[must_use]
+    pub fn recycle(&self, widget: W) -> Recycled<'_, W> {
+        let mut pool = self.inner.borrow_mut();
+        pool.in_use += 1;
+        pool.peak = pool.peak.max(pool.in_use);
+        Recycled {
+            widget: Some(widget),
+            pool: self,
+        }
+    }
+
+    /// Returns the number of idle widgets in the pool.
+    #[must_use]
+    pub fn idle(&self) -> usize {
+        self.inner.borrow().idle.len()
+    }
+
+    /// Drops all idle widgets and resets the size of the pool, e.g. to release
+    /// memory after the list was hidden.
+    pub fn clear(&self) {
+        let mut pool = self.inner.borrow_mut();
+        pool.idle.clear();
+        pool.peak = pool.in_use;
+    }
+
+    /// Hands a widget back to the pool.
+    fn give_back(&self, widget: W) {
+        let mut pool = self.inner.borrow_mut();
+        pool.in_use -= 1;
+        if pool.idle.len() < pool.peak {
+            pool.idle.push(widget);
+        }
+    }
+}
+
+/// An item widget that returns to its [`RecyclePool`] after it was rendered
+/// or dropped, see [`RecyclePool::recycle`].
+#[derive(Debug)]
+pub struct Recycled<'a, W> {
+    widget: Option<W>,
+    pool: &'a RecyclePool<W>,
+}
+
+impl<W> Widget for Recycled<'_, W>
+where
+    for<'b> &'b W: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if let Some(widget) = &self.widget {
+            widget.render(area, buf);
+        }
+    }
+}
+
+impl<W> Drop for Recycled<'_, W> {
+    fn drop(&mut self) {
+        if let Some(widget) = self.widget.take() {
+            self.pool.give_back(widget);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use ratatui::{text::Line, widgets::StatefulWidget};
+
+    use super::*;
+    use crate::{ListBuilder, ListState, ListView};
+
+    struct Canvas(usize);
+
+    impl Widget for &Canvas {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            Line::from(self.0.to_string()).render(area, buf);
+        }
+    }
+
+    #[test]
+    fn reuses_widgets_leaving_the_viewport() {
+        // given
+        let pool = RecyclePool::new();
+        let allocated = Cell::new(0);
+        let mut state = ListState::default();
+        let area = Rect::new(0, 0, 1, 2);
+        let mut buf = Buffer::empty(area);
+
+        // when
+        for selected in [0, 3] {
+            state.select(Some(selected));
+            let builder = ListBuilder::new(|c| {
+                let mut canvas = pool.take().unwrap_or_else(|| {
+                    allocated.set(allocated.get() + 1);
+                    Canvas(0)
+                });
+                canvas.0 = c.index;
+                (pool.recycle(canvas), 1)
+            });
+            ListView::new(builder, 4).render(area, &mut buf, &mut state);
+        }
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(["2", "3"]));
+        assert_eq!(allocated.get(), 2);
+        assert_eq!(pool.idle(), 2);
+    }
+}
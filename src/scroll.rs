@@ -0,0 +1,147 @@
+/// The information that is available to a [`ScrollBehavior`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollContext {
+    /// The index of the selected item, if any.
+    pub selected: Option<usize>,
+
+    /// The index of the first item on the viewport during the last render.
+    pub offset: usize,
+
+    /// The total number of items in the list.
+    pub item_count: usize,
+
+    /// The size of the viewport along the main axis.
+    pub viewport_size: u16,
+
+    /// The scroll padding of the list, see [`ListView::scroll_padding`].
+    ///
+    /// [`ListView::scroll_padding`]: crate::ListView::scroll_padding
+    pub scroll_padding: u16,
+}
+
+/// Decides which item is shown first on the viewport, e.g. to keep the
+/// selected item centered instead of scrolling only as far as necessary.
+///
+/// The behavior is attached to a list with [`ListView::scroll_behavior`]. It is
+/// consulted before every layout. If the selected item does not fit onto the
+/// viewport when starting from the returned item, the list scrolls further
+/// such that the selected item is visible.
+///
+/// [`ListView::scroll_behavior`]: crate::ListView::scroll_behavior
+pub trait ScrollBehavior {
+    /// Returns the index of the first item on the viewport. `item_size` returns
+    /// the size of an item along the main axis.
+    ///
+    /// Returns `None` to leave the first visible item to the layout of the
+    /// list, which scrolls only as far as necessary to keep the selected item
+    /// and its scroll padding visible.
+    fn first_visible(
+        &mut self,
+        context: &ScrollContext,
+        item_size: &mut dyn FnMut(usize) -> u16,
+    ) -> Option<usize>;
+}
+
+/// The default scroll behavior, which leaves the first visible item to the
+/// layout of the list. It always returns `None`, so a list with this behavior
+/// is laid out like a list without a behavior: it scrolls only as far as
+/// necessary to keep the selected item and its scroll padding visible.
+///
+/// It is useful as a fallback of a custom behavior, e.g. to keep the selected
+/// item centered only in some modes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultScrollBehavior;
+
+impl ScrollBehavior for DefaultScrollBehavior {
+    fn first_visible(
+        &mut self,
+        _: &ScrollContext,
+        _: &mut dyn FnMut(usize) -> u16,
+    ) -> Option<usize> {
+        None
+    }
+}
+
+/// Keeps the selected item in the center of the viewport, as far as possible.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CenteredScrollBehavior;
+
+impl ScrollBehavior for CenteredScrollBehavior {
+    fn first_visible(
+        &mut self,
+        context: &ScrollContext,
+        item_size: &mut dyn FnMut(usize) -> u16,
+    ) -> Option<usize> {
+        let selected = context.selected?;
+
+        // The space above the selected item if it was centered.
+        let mut available_size = context.viewport_size.saturating_sub(item_size(selected)) / 2;
+
+        let mut first = selected;
+        while first > 0 {
+            let size = item_size(first - 1);
+            if size > available_size {
+                break;
+            }
+            available_size -= size;
+            first -= 1;
+        }
+
+        Some(first)
+    }
+}
+
+impl<S: ScrollBehavior + ?Sized> ScrollBehavior for &mut S {
+    fn first_visible(
+        &mut self,
+        context: &ScrollContext,
+        item_size: &mut dyn FnMut(usize) -> u16,
+    ) -> Option<usize> {
+        (**self).first_visible(context, item_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{prelude::*, widgets::StatefulWidget};
+
+    use super::*;
+    use crate::{ListBuilder, ListState, ListView};
+
+    #[test]
+    fn centered() {
+        // given
+        let area = Rect::new(0, 0, 3, 5);
+        let mut state = ListState::default();
+        state.select(Some(4));
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+        let list = ListView::new(builder, 10).scroll_behavior(CenteredScrollBehavior);
+
+        // when
+        let mut buf = Buffer::empty(area);
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(state.scroll_offset_index(), 2);
+        assert_eq!(buf, Buffer::with_lines(["2  ", "3  ", "4  ", "5  ", "6  "]));
+    }
+
+    #[test]
+    fn centered_at_start() {
+        // given
+        let mut behavior = CenteredScrollBehavior;
+        let context = ScrollContext {
+            selected: Some(1),
+            offset: 0,
+            item_count: 10,
+            viewport_size: 9,
+            scroll_padding: 0,
+        };
+
+        // when
+        let first = behavior.first_visible(&context, &mut |_| 1);
+
+        // then
+        assert_eq!(first, Some(0));
+    }
+}
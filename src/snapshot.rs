@@ -0,0 +1,140 @@
+use std::fmt;
+
+use ratatui::layout::Rect;
+
+use crate::{ListState, ScrollAxis};
+
+/// The layout of a list that was computed during the last render, see
+/// [`ListState::layout_snapshot`].
+///
+/// The layout is deterministic: it only depends on the state before the
+/// render, the area, the item count and the sizes returned by the builder. It
+/// does not depend on time, randomness or hash ordering. This makes snapshots
+/// suitable for tests that assert the layout instead of the rendered cells.
+///
+/// The snapshot serializes to a stable, line-based text format with its
+/// [`Display`](fmt::Display) implementation, which works well with snapshot
+/// testing tools like `insta`.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+/// use tui_widget_list::{ListBuilder, ListState, ListView};
+///
+/// let area = Rect::new(0, 0, 5, 3);
+/// let mut state = ListState::default();
+/// state.select(Some(3));
+/// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+/// ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+///
+/// assert_eq!(
+///     state.layout_snapshot().to_string(),
+///     "area: 0,0 5x3 vertical\noffset: 1 truncated: 0\nitem 1: 0+1\nitem 2: 1+1\nitem 3: 2+1\n",
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LayoutSnapshot {
+    /// The inner area of the list, i.e. the viewport.
+    pub area: Rect,
+
+    /// The scroll axis of the list.
+    pub scroll_axis: ScrollAxis,
+
+    /// The index of the first item on the viewport.
+    pub offset: usize,
+
+    /// How many rows or columns of the first item are scrolled out of the viewport.
+    pub first_truncated: u16,
+
+    /// The visible items in render order.
+    pub items: Vec<LayoutItem>,
+}
+
+/// The position of a visible item in a [`LayoutSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutItem {
+    /// The index of the item.
+    pub index: usize,
+
+    /// The offset of the item from the start of the viewport along the main axis.
+    pub offset: u16,
+
+    /// The visible size of the item along the main axis.
+    pub size: u16,
+}
+
+impl ListState {
+    /// Returns a snapshot of the layout that was computed during the last render.
+    #[must_use]
+    pub fn layout_snapshot(&self) -> LayoutSnapshot {
+        LayoutSnapshot {
+            area: self.inner_area,
+            scroll_axis: self.scroll_axis,
+            offset: self.view_state.offset,
+            first_truncated: self.view_state.first_truncated,
+            items: self
+                .item_spans
+                .iter()
+                .map(|span| LayoutItem {
+                    index: span.index,
+                    offset: span.offset,
+                    size: span.size,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for LayoutSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let axis = match self.scroll_axis {
+            ScrollAxis::Vertical => "vertical",
+            ScrollAxis::Horizontal => "horizontal",
+        };
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = self.area;
+        writeln!(f, "area: {x},{y} {width}x{height} {axis}")?;
+        writeln!(
+            f,
+            "offset: {} truncated: {}",
+            self.offset, self.first_truncated
+        )?;
+        for item in &self.items {
+            writeln!(f, "item {}: {}+{}", item.index, item.offset, item.size)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{buffer::Buffer, text::Line, widgets::StatefulWidget};
+
+    use super::*;
+    use crate::{ListBuilder, ListView};
+
+    #[test]
+    fn display_format() {
+        // given
+        let area = Rect::new(0, 0, 5, 4);
+        let mut state = ListState::default();
+        state.select(Some(2));
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 2));
+
+        // when
+        ListView::new(builder, 5).render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        let snapshot = state.layout_snapshot();
+        assert_eq!(snapshot.offset, 1);
+        assert_eq!(snapshot.first_truncated, 0);
+        assert_eq!(
+            snapshot.to_string(),
+            "area: 0,0 5x4 vertical\noffset: 1 truncated: 0\nitem 1: 0+2\nitem 2: 2+2\n"
+        );
+    }
+}
@@ -1,3 +1,22 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    ops::Range,
+    time::Duration,
+};
+
+use ratatui::{
+    layout::{Position, Rect},
+    style::Style,
+    text::Text,
+    widgets::ScrollbarState,
+};
+
+use crate::{
+    layout::{SizeCache, Truncation},
+    Easing, KeyChord, ScrollAxis, ScrollPosition,
+};
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
 pub struct ListState {
@@ -8,6 +27,12 @@ pub struct ListState {
     /// handle item selection.
     pub(crate) num_elements: usize,
 
+    /// The item counts of the last renders, oldest first.
+    pub(crate) recent_num_elements: [Option<usize>; 5],
+
+    /// How often the item count alternated, see [`ListState::num_elements_flip_flops`].
+    pub(crate) num_elements_flip_flops: usize,
+
     /// Indicates if the selection is circular. If true, calling `next` on the last
     /// element returns the first, and calling `previous` on the first returns the last.
     ///
@@ -17,10 +42,192 @@ pub struct ListState {
     /// The state for the viewport. Keeps track which item to show
     /// first and how much it is truncated.
     pub(crate) view_state: ViewState,
+
+    /// The inner area of the list during the last render.
+    pub(crate) inner_area: Rect,
+
+    /// The scroll axis of the list during the last render.
+    pub(crate) scroll_axis: ScrollAxis,
+
+    /// Caches the cumulative main axis sizes of the items.
+    pub(crate) size_cache: SizeCache,
+
+    /// Transient style overrides that are patched over the items.
+    pub(crate) item_styles: HashMap<usize, ItemStyle>,
+
+    /// The size adjustments of the items that were resized interactively.
+    pub(crate) size_adjustments: HashMap<usize, i16>,
+
+    /// The animation state of the inline expansion.
+    pub(crate) expansion: ExpansionState,
+
+    /// The selection that was accepted during the last render.
+    pub(crate) accepted_selected: Option<usize>,
+
+    /// The direction of the last step, if the selection was changed by
+    /// [`ListState::next`] or [`ListState::previous`].
+    pub(crate) last_step: Option<Step>,
+
+    /// The ranges of items that are loaded, see [`ListState::mark_loaded`].
+    pub(crate) loaded: Vec<Range<usize>>,
+
+    /// Incremented with every render of a list with placeholders.
+    pub(crate) placeholder_tick: u16,
+
+    /// The positions of the visible items during the last render.
+    pub(crate) item_spans: Vec<ItemSpan>,
+
+    /// Set if the last call to `next` or `previous` wrapped around.
+    pub(crate) wrapped: Option<Wrapped>,
+
+    /// The effective scroll padding of the items. It is recomputed on every
+    /// render but kept to reuse its allocation.
+    pub(crate) scroll_padding_buffer: HashMap<usize, u16>,
+
+    /// The marked items, see [`ListState::set_marked`].
+    pub(crate) marked: BTreeSet<usize>,
+
+    /// The item that is edited, see [`ListState::start_editing`].
+    pub(crate) editing: Option<usize>,
+
+    /// The tooltip and the index of its item, see [`ListState::show_tooltip`].
+    pub(crate) tooltip: Option<(usize, Text<'static>)>,
+
+    /// The interval of the auto-advance, see [`ListState::set_auto_advance`].
+    pub(crate) auto_advance: Option<Duration>,
+
+    /// The time that elapsed since the last auto-advance.
+    pub(crate) auto_advance_elapsed: Duration,
+
+    /// The drag selection in progress, see [`ListState::drag_start`].
+    pub(crate) drag: Option<Drag>,
+
+    /// The shortcut keys of the items, see [`ListState::set_shortcut`].
+    pub(crate) shortcuts: BTreeMap<char, usize>,
+
+    /// The items that were built within the build budget in previous renders.
+    pub(crate) built: BTreeSet<usize>,
+
+    /// Whether the last render built all visible items.
+    pub(crate) render_complete: bool,
+
+    /// The track of the built-in scrollbar during the last render, see
+    /// [`ListView::scrollbar`].
+    ///
+    /// [`ListView::scrollbar`]: crate::ListView::scrollbar
+    pub(crate) scrollbar_area: Rect,
+
+    /// Whether the built-in scrollbar is mirrored, because the list starts from
+    /// the right edge, see [`ListView::right_to_left`].
+    ///
+    /// [`ListView::right_to_left`]: crate::ListView::right_to_left
+    pub(crate) scrollbar_mirrored: bool,
+
+    /// Whether the thumb of the built-in scrollbar is being dragged.
+    pub(crate) scrollbar_drag: bool,
+
+    /// Whether the mouse is over the built-in scrollbar.
+    pub(crate) scrollbar_hovered: bool,
+
+    /// The viewport before the selected item was zoomed, see
+    /// [`ListState::zoom_selected`].
+    pub(crate) zoomed: Option<ViewState>,
+
+    /// Whether the layout starts at the offset instead of the first item if no
+    /// item is selected, see [`ListState::scroll_to`] and
+    /// [`ListView::stateless_scroll`].
+    ///
+    /// [`ListView::stateless_scroll`]: crate::ListView::stateless_scroll
+    pub(crate) offset_anchored: bool,
+
+    /// The index of the first visible item during the last render.
+    pub(crate) rendered_offset: Option<usize>,
+
+    /// The direction in which the viewport moved most recently.
+    pub(crate) scroll_direction: Option<Step>,
+
+    /// The number of items that the viewport moved during the last render.
+    pub(crate) scroll_velocity: usize,
+
+    /// The key presses of an incomplete key binding, see [`ListState::handle_key`].
+    pub(crate) pending_keys: Vec<KeyChord>,
+
+    /// The last viewport position that was published to a [`ScrollSync`].
+    ///
+    /// [`ScrollSync`]: crate::ScrollSync
+    pub(crate) scroll_position: Option<ScrollPosition>,
+}
+
+/// A style override of a single item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ItemStyle {
+    /// The style that is patched over the item.
+    pub(crate) style: Style,
+
+    /// The number of renders after which the override is removed.
+    /// If `None`, the override is kept until it is cleared explicitly.
+    pub(crate) remaining_renders: Option<u16>,
+
+    /// If set, the style fades out over the given number of renders.
+    pub(crate) fade_renders: Option<u16>,
+}
+
+/// The position of a visible item along the main axis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ItemSpan {
+    /// The index of the item.
+    pub(crate) index: usize,
+
+    /// The offset of the item from the start of the viewport.
+    pub(crate) offset: u16,
+
+    /// The visible size of the item.
+    pub(crate) size: u16,
+
+    /// The area of the item on the buffer.
+    pub(crate) area: Rect,
+
+    /// How much of the item is cut off at the edges of the viewport.
+    pub(crate) truncation: Truncation,
+}
+
+/// A drag selection in progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Drag {
+    /// The item where the drag started.
+    pub(crate) anchor: usize,
+
+    /// The items that were marked before the drag started.
+    pub(crate) marked: BTreeSet<usize>,
+}
+
+/// Describes how the selection wrapped around with infinite scrolling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wrapped {
+    /// The selection wrapped from the last to the first item.
+    ToFirst,
+
+    /// The selection wrapped from the first to the last item.
+    ToLast,
+}
+
+/// The direction in which the selection was moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Step {
+    Next,
+    Previous,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
-pub(crate) struct ViewState {
+/// The position of the viewport, i.e. which item is shown first and how much
+/// it is truncated.
+///
+/// The view state is part of the [`ListState`], but it can also be owned
+/// separately to render the same selection into several viewports with
+/// [`ListView::render_with_view_state`].
+///
+/// [`ListView::render_with_view_state`]: crate::ListView::render_with_view_state
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct ViewState {
     /// The index of the first item displayed on the screen.
     pub(crate) offset: usize,
 
@@ -28,13 +235,124 @@ pub(crate) struct ViewState {
     pub(crate) first_truncated: u16,
 }
 
+impl ViewState {
+    /// Returns the index of the first item on the viewport.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns how many rows or columns of the first item are scrolled out of
+    /// the viewport.
+    #[must_use]
+    pub fn first_truncated(&self) -> u16 {
+        self.first_truncated
+    }
+}
+
+/// Tracks the animated expansion and collapse of the items' detail widgets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ExpansionState {
+    /// The expanding item and the number of frames it has been expanding.
+    pub(crate) expanding: Option<(usize, u16)>,
+
+    /// The previously expanded item and the number of frames until it is collapsed.
+    pub(crate) collapsing: Option<(usize, u16)>,
+
+    /// The number of frames of a transition. Zero disables the animation.
+    pub(crate) frames: u16,
+
+    /// The easing curve of a transition.
+    pub(crate) easing: Easing,
+}
+
+impl ExpansionState {
+    /// Advances the animation by one frame. If the selection changed, the
+    /// previously selected item starts to collapse and the selected one expands.
+    pub(crate) fn advance(&mut self, selected: Option<usize>, frames: u16, easing: Easing) {
+        self.frames = frames;
+        self.easing = easing;
+        if self.expanding.map(|(index, _)| index) != selected {
+            let previous = self.expanding;
+            // Continue from the current size if the item is still collapsing.
+            self.expanding = match (selected, self.collapsing) {
+                (Some(index), Some((collapsing, progress))) if index == collapsing => {
+                    Some((index, progress))
+                }
+                (Some(index), _) => Some((index, 0)),
+                (None, _) => None,
+            };
+            self.collapsing = previous;
+        }
+        if let Some((_, progress)) = &mut self.expanding {
+            *progress = progress.saturating_add(1).min(frames);
+        }
+        if let Some((_, progress)) = &mut self.collapsing {
+            *progress = progress.saturating_sub(1).min(frames);
+        }
+        if matches!(self.collapsing, Some((_, 0))) {
+            self.collapsing = None;
+        }
+    }
+
+    /// Returns true if the item at `index` is expanding or collapsing.
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        self.expanding.is_some_and(|(i, _)| i == index)
+            || self.collapsing.is_some_and(|(i, _)| i == index)
+    }
+
+    /// Scales the full expansion size of the item at `index` by its animation progress.
+    pub(crate) fn scale(&self, index: usize, size: u16) -> u16 {
+        let progress = match (self.expanding, self.collapsing) {
+            (Some((i, progress)), _) | (_, Some((i, progress))) if i == index => progress,
+            _ => return 0,
+        };
+        self.easing.interpolate(0, size, progress, self.frames)
+    }
+}
+
 impl Default for ListState {
     fn default() -> Self {
         Self {
             selected: None,
             num_elements: 0,
+            recent_num_elements: [None; 5],
+            num_elements_flip_flops: 0,
             infinite_scrolling: true,
             view_state: ViewState::default(),
+            inner_area: Rect::default(),
+            scroll_axis: ScrollAxis::default(),
+            size_cache: SizeCache::default(),
+            item_styles: HashMap::new(),
+            scroll_position: None,
+            marked: BTreeSet::new(),
+            drag: None,
+            shortcuts: BTreeMap::new(),
+            built: BTreeSet::new(),
+            render_complete: true,
+            scrollbar_area: Rect::default(),
+            scrollbar_mirrored: false,
+            scrollbar_drag: false,
+            scrollbar_hovered: false,
+            zoomed: None,
+            offset_anchored: false,
+            rendered_offset: None,
+            scroll_direction: None,
+            scroll_velocity: 0,
+            pending_keys: Vec::new(),
+            tooltip: None,
+            editing: None,
+            auto_advance: None,
+            auto_advance_elapsed: Duration::ZERO,
+            size_adjustments: HashMap::new(),
+            expansion: ExpansionState::default(),
+            accepted_selected: None,
+            last_step: None,
+            scroll_padding_buffer: HashMap::new(),
+            wrapped: None,
+            item_spans: Vec::new(),
+            loaded: Vec::new(),
+            placeholder_tick: 0,
         }
     }
 }
@@ -51,9 +369,34 @@ impl ListState {
         self.selected
     }
 
+    /// Returns the selected item if it is within the first `item_count` items,
+    /// e.g. to validate a stored selection after the data shrunk.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.select(Some(3));
+    /// assert_eq!(state.selected_checked(5), Some(3));
+    /// assert_eq!(state.selected_checked(3), None);
+    /// ```
+    #[must_use]
+    pub fn selected_checked(&self, item_count: usize) -> Option<usize> {
+        self.selected.filter(|&index| index < item_count)
+    }
+
+    /// Returns the number of items of the list during the last render.
+    #[must_use]
+    pub fn num_elements(&self) -> usize {
+        self.num_elements
+    }
+
     /// Selects an item by its index.
     pub fn select(&mut self, index: Option<usize>) {
         self.selected = index;
+        self.last_step = None;
         if index.is_none() {
             self.view_state.offset = 0;
         }
@@ -71,13 +414,14 @@ impl ListState {
     /// list_state.next();
     /// ```
     pub fn next(&mut self) {
-        if self.num_elements == 0 {
+        if self.num_elements == 0 || self.editing.is_some() {
             return;
         }
         let i = match self.selected {
             Some(i) => {
                 if i >= self.num_elements - 1 {
                     if self.infinite_scrolling {
+                        self.wrapped = Some(Wrapped::ToFirst);
                         0
                     } else {
                         i
@@ -89,6 +433,7 @@ impl ListState {
             None => 0,
         };
         self.select(Some(i));
+        self.last_step = Some(Step::Next);
     }
 
     /// Selects the previous element of the list. If circular is true,
@@ -103,13 +448,14 @@ impl ListState {
     /// list_state.previous();
     /// ```
     pub fn previous(&mut self) {
-        if self.num_elements == 0 {
+        if self.num_elements == 0 || self.editing.is_some() {
             return;
         }
         let i = match self.selected {
             Some(i) => {
                 if i == 0 {
                     if self.infinite_scrolling {
+                        self.wrapped = Some(Wrapped::ToLast);
                         self.num_elements - 1
                     } else {
                         i
@@ -121,16 +467,1642 @@ impl ListState {
             None => 0,
         };
         self.select(Some(i));
+        self.last_step = Some(Step::Previous);
+    }
+
+    /// Moves the selection by `n` items, forwards for positive and backwards
+    /// for negative values, e.g. for count-prefixed navigation like `5j`.
+    ///
+    /// With infinite scrolling, the selection wraps around the ends of the list
+    /// like [`ListState::next`] and [`ListState::previous`], otherwise it stops
+    /// at the first or the last item. If no item is selected, the first item is
+    /// selected.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 4, 3);
+    /// let mut state = ListState::default();
+    /// state.select(Some(8));
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let list = ListView::new(builder, 10).infinite_scrolling(true);
+    /// list.render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// state.select_relative(5);
+    /// assert_eq!(state.selected, Some(3));
+    ///
+    /// state.select_relative(-2);
+    /// assert_eq!(state.selected, Some(1));
+    /// ```
+    pub fn select_relative(&mut self, n: isize) {
+        if self.num_elements == 0 || self.editing.is_some() {
+            return;
+        }
+        let Some(i) = self.selected else {
+            self.select(Some(0));
+            return;
+        };
+        if n == 0 {
+            return;
+        }
+        let target = i.checked_add_signed(n).filter(|&j| j < self.num_elements);
+        let index = match target {
+            Some(j) => j,
+            None if self.infinite_scrolling => {
+                let len = self.num_elements;
+                let delta = n.unsigned_abs() % len;
+                if n > 0 {
+                    self.wrapped = Some(Wrapped::ToFirst);
+                    (i + delta) % len
+                } else {
+                    self.wrapped = Some(Wrapped::ToLast);
+                    (i + len - delta) % len
+                }
+            }
+            None if n > 0 => self.num_elements - 1,
+            None => 0,
+        };
+        self.select(Some(index));
+        self.last_step = Some(if n > 0 { Step::Next } else { Step::Previous });
+    }
+
+    /// Selects the first item, e.g. on the `Home` key. Works the same for
+    /// vertical and horizontal lists.
+    pub fn select_first(&mut self) {
+        if self.num_elements == 0 || self.editing.is_some() {
+            return;
+        }
+        self.select(Some(0));
+        self.last_step = Some(Step::Next);
+    }
+
+    /// Selects the last item, e.g. on the `End` key. Works the same for
+    /// vertical and horizontal lists.
+    pub fn select_last(&mut self) {
+        if self.num_elements == 0 || self.editing.is_some() {
+            return;
+        }
+        self.select(Some(self.num_elements - 1));
+        self.last_step = Some(Step::Previous);
+    }
+
+    /// Moves the selection one page towards the end of the list, e.g. on the
+    /// `PageDown` key. The selection moves to the last item that ends within
+    /// one viewport from the start of the selected item, measured with the
+    /// viewport size and the item sizes of the last render, such that items of
+    /// different heights are paged by screenful rather than by count. Items
+    /// that were not visible are assumed to have the average size of the
+    /// visible ones. The selection moves by at least one item and does not
+    /// wrap.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView, ScrollAxis};
+    ///
+    /// let area = Rect::new(0, 0, 4, 1);
+    /// let mut state = ListState::default();
+    /// state.select(Some(0));
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let list = ListView::new(builder, 10).scroll_axis(ScrollAxis::Horizontal);
+    /// list.render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// state.next_page();
+    /// assert_eq!(state.selected, Some(3));
+    /// ```
+    pub fn next_page(&mut self) {
+        if self.num_elements == 0 || self.editing.is_some() {
+            return;
+        }
+        let index = self.selected.map_or(0, |i| self.page_target(i, true));
+        self.select(Some(index.min(self.num_elements - 1)));
+        self.last_step = Some(Step::Next);
+    }
+
+    /// Moves the selection one page towards the start of the list, e.g. on the
+    /// `PageUp` key. See [`ListState::next_page`].
+    pub fn previous_page(&mut self) {
+        if self.num_elements == 0 || self.editing.is_some() {
+            return;
+        }
+        let index = self.selected.map_or(0, |i| self.page_target(i, false));
+        self.select(Some(index.min(self.num_elements - 1)));
+        self.last_step = Some(Step::Previous);
+    }
+
+    /// Returns the item that is one screenful away from `selected`, measured
+    /// with the item sizes of the last render.
+    fn page_target(&self, selected: usize, down: bool) -> usize {
+        let main_axis_size = self.scroll_axis.main_size(self.inner_area);
+        let sizes: HashMap<usize, u16> = self
+            .item_spans
+            .iter()
+            .map(|span| (span.index, span.size + span.truncation.value()))
+            .collect();
+        let known: u32 = sizes.values().map(|&size| u32::from(size)).sum();
+        let average = u32::try_from(sizes.len())
+            .ok()
+            .and_then(|count| known.checked_div(count))
+            .map_or(1, |average| {
+                u16::try_from(average).unwrap_or(u16::MAX).max(1)
+            });
+        let size = |index: usize| sizes.get(&index).copied().unwrap_or(average);
+
+        let mut target = selected;
+        let mut used = size(selected);
+        while used < main_axis_size {
+            let next = if down {
+                Some(target + 1).filter(|&next| next < self.num_elements)
+            } else {
+                target.checked_sub(1)
+            };
+            let Some(next) = next else {
+                break;
+            };
+            used = used.saturating_add(size(next));
+            if used > main_axis_size {
+                break;
+            }
+            target = next;
+        }
+
+        // Move by at least one item.
+        if target != selected {
+            target
+        } else if down {
+            selected + 1
+        } else {
+            selected.saturating_sub(1)
+        }
+    }
+
+    /// Returns the number of items that a page moves the selection.
+    fn page_size(&self) -> usize {
+        self.item_spans.len().saturating_sub(1).max(1)
+    }
+
+    /// Moves the selection half a page down or up. The selection does not wrap.
+    pub(crate) fn move_by_half_page(&mut self, down: bool) {
+        if self.num_elements == 0 || self.editing.is_some() {
+            return;
+        }
+        let half_page = (self.page_size() / 2).max(1);
+        let (index, step) = match self.selected {
+            None => (0, Step::Next),
+            Some(i) if down => ((i + half_page).min(self.num_elements - 1), Step::Next),
+            Some(i) => (i.saturating_sub(half_page), Step::Previous),
+        };
+        self.select(Some(index));
+        self.last_step = Some(step);
+    }
+
+    /// Returns and clears the wrap-around event of the selection, if
+    /// [`ListState::next`] or [`ListState::previous`] wrapped around since the
+    /// last call, e.g. to show a "wrapped to top" hint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::{ListState, Wrapped};
+    ///
+    /// let mut list_state = ListState::default();
+    /// if list_state.take_wrapped() == Some(Wrapped::ToFirst) {
+    ///     // Show a hint
+    /// }
+    /// ```
+    pub fn take_wrapped(&mut self) -> Option<Wrapped> {
+        self.wrapped.take()
+    }
+
+    /// Sets a style that is patched over the item at `index`, e.g. to highlight
+    /// an item after a successful action. The style is kept until it is cleared
+    /// with [`ListState::clear_item_style`].
+    pub fn set_item_style(&mut self, index: usize, style: Style) {
+        let item_style = ItemStyle {
+            style,
+            remaining_renders: None,
+            fade_renders: None,
+        };
+        self.item_styles.insert(index, item_style);
+    }
+
+    /// Sets a style that is patched over the item at `index` for the next
+    /// `renders` renders. Afterwards, the style is cleared automatically.
+    pub fn set_item_style_for(&mut self, index: usize, style: Style, renders: u16) {
+        let item_style = ItemStyle {
+            style,
+            remaining_renders: Some(renders),
+            fade_renders: None,
+        };
+        self.item_styles.insert(index, item_style);
+    }
+
+    /// Flashes the item at `index` with the given style. The flash decays with
+    /// every render and disappears after `duration_frames` renders, e.g. to give
+    /// feedback that a row was updated.
+    ///
+    /// Rgb colors are faded out smoothly, other colors and modifiers are applied
+    /// during the first half of the flash.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut list_state = ListState::default();
+    /// list_state.flash(0, Style::default().bg(Color::Rgb(0, 255, 0)), 10);
+    /// ```
+    pub fn flash(&mut self, index: usize, style: Style, duration_frames: u16) {
+        let item_style = ItemStyle {
+            style,
+            remaining_renders: Some(duration_frames),
+            fade_renders: Some(duration_frames),
+        };
+        self.item_styles.insert(index, item_style);
+    }
+
+    /// Returns the style override of the item at `index`, if any.
+    #[must_use]
+    pub fn item_style(&self, index: usize) -> Option<Style> {
+        self.item_styles
+            .get(&index)
+            .map(|item_style| item_style.style)
+    }
+
+    /// Clears the style override of the item at `index`.
+    pub fn clear_item_style(&mut self, index: usize) {
+        self.item_styles.remove(&index);
+    }
+
+    /// Clears the style overrides of all items.
+    pub fn clear_item_styles(&mut self) {
+        self.item_styles.clear();
+    }
+
+    /// Grows or shrinks the selected item along the main axis by `delta`, e.g.
+    /// to let the user adjust the height of a pane inside the list. The resized
+    /// size is kept within the bounds of [`ListBuilder::size_bounds`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut list_state = ListState::default();
+    /// list_state.select(Some(0));
+    /// list_state.resize_selected(2);
+    /// ```
+    ///
+    /// [`ListBuilder::size_bounds`]: crate::ListBuilder::size_bounds
+    pub fn resize_selected(&mut self, delta: i16) {
+        if let Some(index) = self.selected {
+            let adjustment = self.size_adjustments.entry(index).or_default();
+            *adjustment = adjustment.saturating_add(delta);
+            self.invalidate_item_offsets();
+        }
+    }
+
+    /// Resets the sizes of all items that were resized with
+    /// [`ListState::resize_selected`].
+    pub fn reset_item_sizes(&mut self) {
+        self.size_adjustments.clear();
+        self.invalidate_item_offsets();
+    }
+
+    /// Drops the item sizes cached by [`ListView::item_offset`].
+    ///
+    /// The cache is invalidated when the selection, the viewport or the item
+    /// sizes managed by the state change. If the sizes returned by the builder
+    /// change for other reasons, e.g. because the data of the items changed,
+    /// this must be called before querying the offsets again.
+    ///
+    /// [`ListView::item_offset`]: crate::ListView::item_offset
+    pub fn invalidate_item_offsets(&mut self) {
+        self.size_cache = SizeCache::default();
+    }
+
+    /// Counts down the remaining renders of the style overrides and removes
+    /// the ones that have expired.
+    pub(crate) fn tick_item_styles(&mut self) {
+        self.item_styles
+            .retain(|_, item_style| match &mut item_style.remaining_renders {
+                Some(remaining_renders) => {
+                    *remaining_renders = remaining_renders.saturating_sub(1);
+                    *remaining_renders > 0
+                }
+                None => true,
+            });
+    }
+
+    /// Consults `is_valid` if the selection changed since the last render. If
+    /// the new selection is rejected and it was reached by stepping through the
+    /// list, the selection continues in the same direction until an item is
+    /// accepted. Otherwise, the previous selection is restored.
+    pub(crate) fn filter_selection<F>(&mut self, is_valid: F)
+    where
+        F: Fn(Option<usize>, Option<usize>) -> bool,
+    {
+        let from = self.accepted_selected;
+        if self.selected != from {
+            let mut accepted = is_valid(from, self.selected);
+            if let Some(step) = self.last_step {
+                for _ in 1..self.num_elements {
+                    if accepted {
+                        break;
+                    }
+                    let previous = self.selected;
+                    match step {
+                        Step::Next => self.next(),
+                        Step::Previous => self.previous(),
+                    }
+                    if self.selected == previous || self.selected == from {
+                        break;
+                    }
+                    accepted = is_valid(from, self.selected);
+                }
+            }
+            if !accepted {
+                self.selected = from;
+            }
+        }
+        self.accepted_selected = self.selected;
+        self.last_step = None;
+    }
+
+    /// Updates the inner area and the scroll axis of the last render.
+    ///
+    /// If the size of the area changed, the first item is kept anchored at the
+    /// start of the viewport. Its truncation was computed for the previous size
+    /// and is dropped, such that the item is shown from its start.
+    pub(crate) fn set_inner_area(&mut self, inner_area: Rect, scroll_axis: ScrollAxis) {
+        let is_resize = !self.inner_area.is_empty()
+            && (self.inner_area.width, self.inner_area.height)
+                != (inner_area.width, inner_area.height);
+        if is_resize {
+            self.view_state.first_truncated = 0;
+        }
+        self.inner_area = inner_area;
+        self.scroll_axis = scroll_axis;
+    }
+
+    /// Returns how often the item count of the list alternated between two
+    /// values on consecutive renders, e.g. `3, 5, 3, 5, 3, 5`.
+    ///
+    /// This usually indicates that the `ListState` is shared between lists with
+    /// different item counts, which makes the selection and the scroll position
+    /// jump. Applications that legitimately toggle the item count, e.g. with a
+    /// filter, can ignore it.
+    ///
+    /// # Example
+    /// ```
+    /// use tui_widget_list::ListState;
+    ///
+    /// let state = ListState::default();
+    /// assert_eq!(state.num_elements_flip_flops(), 0);
+    /// ```
+    #[must_use]
+    pub fn num_elements_flip_flops(&self) -> usize {
+        self.num_elements_flip_flops
     }
 
     /// Updates the number of elements that are present in the list.
     pub(crate) fn set_num_elements(&mut self, num_elements: usize) {
+        // Item counts that keep alternating between two values on consecutive
+        // renders, e.g. `3, 5, 3, 5, 3, 5`, indicate a state that is shared
+        // between two lists.
+        let recent = self.recent_num_elements;
+        let current = Some(num_elements);
+        let alternating = recent[0].is_some()
+            && recent[0] != current
+            && recent.iter().step_by(2).all(|&n| n == recent[0])
+            && recent.iter().skip(1).step_by(2).all(|&n| n == current);
+        if alternating {
+            self.num_elements_flip_flops += 1;
+        }
+        self.recent_num_elements.rotate_left(1);
+        self.recent_num_elements[4] = current;
         self.num_elements = num_elements;
     }
 
-    /// Returns the index of the first item currently displayed on the screen.
+    /// Marks the items in `range` as loaded. Items that are not loaded are
+    /// rendered as placeholders if [`ListView::placeholder`] is configured.
+    ///
+    /// [`ListView::placeholder`]: crate::ListView::placeholder
+    pub fn mark_loaded(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        // Merge the range with the overlapping and adjacent ranges.
+        let mut merged = range;
+        self.loaded.retain(|loaded| {
+            let overlaps = loaded.start <= merged.end && merged.start <= loaded.end;
+            if overlaps {
+                merged = merged.start.min(loaded.start)..merged.end.max(loaded.end);
+            }
+            !overlaps
+        });
+        self.loaded.push(merged);
+        self.loaded.sort_by_key(|loaded| loaded.start);
+        self.invalidate_item_offsets();
+    }
+
+    /// Marks all items as not loaded, e.g. after the data source was reset.
+    pub fn clear_loaded(&mut self) {
+        self.loaded.clear();
+        self.invalidate_item_offsets();
+    }
+
+    /// Returns the indices of the items that were visible during the last
+    /// render, in render order. The iterator borrows the layout of the last
+    /// render and does not allocate, which makes it cheap for post-render
+    /// passes like metrics or overlays.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 3);
+    /// let mut state = ListState::default();
+    /// state.select(Some(4));
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert_eq!(state.visible_indices().collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// ```
+    pub fn visible_indices(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator + '_ {
+        self.item_spans.iter().map(|span| span.index)
+    }
+
+    /// Returns the index of the item that is displayed at the given offset along
+    /// the main axis, measured in cells from the start of the viewport during
+    /// the last render. Returns `None` if no item is displayed there.
+    ///
+    /// This is useful to correlate external gutters, time scales or mouse
+    /// drags with an item.
     #[must_use]
-    pub fn scroll_offset_index(&self) -> usize {
-        self.view_state.offset
+    pub fn index_at_offset(&self, cells: u16) -> Option<usize> {
+        self.item_spans
+            .iter()
+            .find(|span| span.offset <= cells && cells < span.offset + span.size)
+            .map(|span| span.index)
+    }
+
+    /// Zooms into the selected item, such that it is rendered over the entire
+    /// inner area of the list while the other items are hidden, e.g. to preview
+    /// an entry full-screen. The builder receives the size of the inner area as
+    /// the remaining main axis size.
+    ///
+    /// Zooming out restores the viewport from before zooming in. Nothing is
+    /// rendered zoomed while no item is selected.
+    pub fn zoom_selected(&mut self, zoomed: bool) {
+        match (zoomed, self.zoomed) {
+            (true, None) => self.zoomed = Some(self.view_state),
+            (false, Some(view_state)) => {
+                self.view_state = view_state;
+                self.zoomed = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns true if the selected item is zoomed, see [`ListState::zoom_selected`].
+    #[must_use]
+    pub fn is_zoomed(&self) -> bool {
+        self.zoomed.is_some()
+    }
+
+    /// Returns the index of the item that is displayed at the given terminal
+    /// position during the last render, e.g. for applications with their own
+    /// mouse handling. Partially visible items at the edges of the viewport
+    /// are hit by their visible part. Returns `None` if no item is displayed
+    /// there, e.g. on the block, the gutter or the scrollbar.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::{Position, Rect}, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 5);
+    /// let mut state = ListState::default();
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 2));
+    /// ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert_eq!(state.item_at_position(Position::new(3, 3)), Some(1));
+    /// assert_eq!(state.item_at_position(Position::new(3, 4)), Some(2));
+    /// assert_eq!(state.item_at_position(Position::new(3, 5)), None);
+    /// ```
+    #[must_use]
+    pub fn item_at_position(&self, position: Position) -> Option<usize> {
+        self.item_spans
+            .iter()
+            .find(|span| span.area.contains(position))
+            .map(|span| span.index)
+    }
+
+    /// Scrolls the viewport such that the selected item is fully visible,
+    /// without waiting for the next render. This is useful if the selection is
+    /// changed from background events and the application needs the viewport
+    /// position, e.g. for [`ListState::index_at_offset`], before the next frame.
+    ///
+    /// The viewport size and the item sizes are taken from the last render.
+    /// Items that were not visible are assumed to have the average size of the
+    /// visible ones. The next render computes the exact layout, including the
+    /// scroll padding, starting from the corrected viewport.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 3);
+    /// let mut state = ListState::default();
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// state.select(Some(6));
+    /// state.ensure_selected_visible();
+    /// assert_eq!(state.scroll_offset_index(), 4);
+    /// ```
+    pub fn ensure_selected_visible(&mut self) {
+        let (Some(selected), Some(last)) = (self.selected, self.item_spans.last()) else {
+            return;
+        };
+        let ViewState {
+            offset,
+            first_truncated,
+        } = self.view_state;
+
+        // Align the selected item to the start of the viewport.
+        if selected < offset || (selected == offset && first_truncated > 0) {
+            self.view_state = ViewState {
+                offset: selected,
+                first_truncated: 0,
+            };
+            return;
+        }
+
+        // The selected item is visible, unless it is the last item, which may be truncated.
+        if selected < last.index || (selected == last.index && last.truncation.value() == 0) {
+            return;
+        }
+
+        // Align the selected item to the end of the viewport.
+        let main_axis_size = self.scroll_axis.main_size(self.inner_area);
+        let sizes: HashMap<usize, u16> = self
+            .item_spans
+            .iter()
+            .map(|span| (span.index, span.size + span.truncation.value()))
+            .collect();
+        let known: u32 = sizes.values().map(|&size| u32::from(size)).sum();
+        let average = u32::try_from(sizes.len())
+            .ok()
+            .and_then(|count| known.checked_div(count))
+            .map_or(1, |average| {
+                u16::try_from(average).unwrap_or(u16::MAX).max(1)
+            });
+        let mut remaining = main_axis_size;
+        let mut index = selected;
+        self.view_state = loop {
+            let size = sizes.get(&index).copied().unwrap_or(average);
+            if size >= remaining {
+                break ViewState {
+                    offset: index,
+                    first_truncated: size - remaining,
+                };
+            }
+            if index == 0 {
+                break ViewState::default();
+            }
+            remaining -= size;
+            index -= 1;
+        };
+    }
+
+    /// Enables or disables the auto-advance, e.g. for carousels. If enabled,
+    /// [`ListState::tick`] selects the next item whenever `interval` elapsed.
+    /// The selection wraps around if infinite scrolling is enabled and stops
+    /// at the last item otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.set_auto_advance(Some(Duration::from_millis(500)));
+    /// ```
+    pub fn set_auto_advance(&mut self, interval: Option<Duration>) {
+        self.auto_advance = interval.filter(|interval| !interval.is_zero());
+        self.auto_advance_elapsed = Duration::ZERO;
+    }
+
+    /// Advances the time of the auto-advance by `dt`, e.g. the time since the
+    /// last frame, and selects the next item for every elapsed interval. Does
+    /// nothing if the auto-advance is disabled.
+    pub fn tick(&mut self, dt: Duration) {
+        let Some(interval) = self.auto_advance else {
+            return;
+        };
+        self.auto_advance_elapsed += dt;
+        while self.auto_advance_elapsed >= interval {
+            self.auto_advance_elapsed -= interval;
+            self.next();
+        }
+    }
+
+    /// Notifies the state that `count` items were inserted at the start of the
+    /// list, e.g. new entries of a notification or history feed. The selection,
+    /// the viewport and the state of individual items are shifted, such that
+    /// the visible content does not jump.
+    ///
+    /// If no item is selected, the list keeps showing the first items, i.e.
+    /// the newly inserted ones.
+    ///
+    /// # Example
+    /// ```
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.select(Some(2));
+    /// state.notify_prepended(3);
+    /// assert_eq!(state.selected, Some(5));
+    /// ```
+    pub fn notify_prepended(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+        let shift = |index: usize| index.saturating_add(count);
+        self.selected = self.selected.map(shift);
+        self.accepted_selected = self.accepted_selected.map(shift);
+        self.editing = self.editing.map(shift);
+        self.num_elements = shift(self.num_elements);
+        self.view_state.offset = shift(self.view_state.offset);
+        if let Some((index, _)) = &mut self.tooltip {
+            *index = shift(*index);
+        }
+        if let Some(drag) = &mut self.drag {
+            drag.anchor = shift(drag.anchor);
+            drag.marked = drag.marked.iter().map(|&index| shift(index)).collect();
+        }
+        for (index, _) in self
+            .expansion
+            .expanding
+            .iter_mut()
+            .chain(&mut self.expansion.collapsing)
+        {
+            *index = shift(*index);
+        }
+        for span in &mut self.item_spans {
+            span.index = shift(span.index);
+        }
+        for range in &mut self.loaded {
+            *range = shift(range.start)..shift(range.end);
+        }
+        self.marked = self.marked.iter().map(|&index| shift(index)).collect();
+        self.built = self.built.iter().map(|&index| shift(index)).collect();
+        self.size_adjustments = self
+            .size_adjustments
+            .drain()
+            .map(|(index, adjustment)| (shift(index), adjustment))
+            .collect();
+        self.item_styles = self
+            .item_styles
+            .drain()
+            .map(|(index, style)| (shift(index), style))
+            .collect();
+        self.size_cache = SizeCache::default();
+    }
+
+    /// Starts editing the selected item. While an item is edited, it is
+    /// replaced by the editor of the list, see [`ListView::editor`], and
+    /// [`ListState::next`] and [`ListState::previous`] do nothing.
+    ///
+    /// Does nothing if no item is selected.
+    ///
+    /// [`ListView::editor`]: crate::ListView::editor
+    pub fn start_editing(&mut self) {
+        self.editing = self.selected;
+    }
+
+    /// Stops editing, see [`ListState::start_editing`].
+    pub fn stop_editing(&mut self) {
+        self.editing = None;
+    }
+
+    /// Returns the index of the item that is edited, if any.
+    #[must_use]
+    pub fn editing(&self) -> Option<usize> {
+        self.editing
+    }
+
+    /// Shows a tooltip anchored to the item at `index`, e.g. to reveal the full
+    /// content of a truncated item. The tooltip is rendered above the list,
+    /// below the item or above it if there is not enough space, and clipped to
+    /// the frame. It is only rendered while the item is visible.
+    ///
+    /// The tooltip is shown until it is hidden with [`ListState::hide_tooltip`]
+    /// or replaced by another tooltip.
+    pub fn show_tooltip<T: Into<Text<'static>>>(&mut self, index: usize, text: T) {
+        self.tooltip = Some((index, text.into()));
+    }
+
+    /// Hides the tooltip, see [`ListState::show_tooltip`].
+    pub fn hide_tooltip(&mut self) {
+        self.tooltip = None;
+    }
+
+    /// Returns the marked items in ascending order.
+    #[must_use]
+    pub fn marked(&self) -> &BTreeSet<usize> {
+        &self.marked
+    }
+
+    /// Returns true if the item at `index` is marked.
+    #[must_use]
+    pub fn is_marked(&self, index: usize) -> bool {
+        self.marked.contains(&index)
+    }
+
+    /// Marks or unmarks the item at `index`. Marked items are rendered with
+    /// [`ListBuildContext::is_marked`] set, e.g. to select several items at once.
+    ///
+    /// [`ListBuildContext::is_marked`]: crate::ListBuildContext::is_marked
+    pub fn set_marked(&mut self, index: usize, marked: bool) {
+        if marked {
+            self.marked.insert(index);
+        } else {
+            self.marked.remove(&index);
+        }
+        self.invalidate_item_offsets();
+    }
+
+    /// Unmarks all items.
+    pub fn clear_marked(&mut self) {
+        self.marked.clear();
+        self.invalidate_item_offsets();
+    }
+
+    /// Marks exactly the items among the first `item_count` items for which
+    /// `predicate` returns true, e.g. to mark all failed jobs. Marks of other
+    /// items are removed and a drag selection in progress is ended.
+    ///
+    /// # Example
+    /// ```
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.select_where(6, |index| index % 2 == 0);
+    /// assert_eq!(state.marked().iter().copied().collect::<Vec<_>>(), vec![0, 2, 4]);
+    /// ```
+    pub fn select_where<F: FnMut(usize) -> bool>(&mut self, item_count: usize, mut predicate: F) {
+        self.drag = None;
+        self.marked.clear();
+        self.marked
+            .extend((0..item_count).filter(|&index| predicate(index)));
+        self.invalidate_item_offsets();
+    }
+
+    /// Starts a drag selection at the given terminal position, e.g. when the
+    /// mouse button is pressed. Selects and marks the item under the position
+    /// during the last render and returns its index, or returns `None` if no
+    /// item is displayed there.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::{Position, Rect}, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 5);
+    /// let mut state = ListState::default();
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// state.drag_start(Position::new(0, 1));
+    /// state.drag_to(Position::new(0, 3));
+    /// state.drag_end();
+    /// assert_eq!(state.marked().iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn drag_start(&mut self, position: Position) -> Option<usize> {
+        if self.editing.is_some() || !self.inner_area.contains(position) {
+            return None;
+        }
+        let offset = u16::try_from(self.main_axis_offset(position)).ok()?;
+        let index = self.index_at_offset(offset)?;
+        self.drag = Some(Drag {
+            anchor: index,
+            marked: self.marked.clone(),
+        });
+        self.select(Some(index));
+        self.marked.insert(index);
+        self.invalidate_item_offsets();
+        Some(index)
+    }
+
+    /// Extends the drag selection to the given terminal position, e.g. when the
+    /// mouse is moved with the button pressed. The items from the start of the
+    /// drag to the item under the position are marked and the item under the
+    /// position is selected.
+    ///
+    /// If the position lies on or beyond the edge of the viewport, the item
+    /// behind the edge is selected instead, such that the list scrolls on the
+    /// next render. Does nothing if no drag is in progress.
+    pub fn drag_to(&mut self, position: Position) {
+        let Some(anchor) = self.drag.as_ref().map(|drag| drag.anchor) else {
+            return;
+        };
+        let (Some(first), Some(last)) = (self.item_spans.first(), self.item_spans.last()) else {
+            return;
+        };
+        let offset = self.main_axis_offset(position);
+        let main_axis_size = i32::from(self.scroll_axis.main_size(self.inner_area));
+        let index = if offset <= 0 {
+            first.index.saturating_sub(1)
+        } else if offset >= main_axis_size - 1 {
+            (last.index + 1).min(self.num_elements.saturating_sub(1))
+        } else {
+            // Positions behind the last item extend the drag to the last item.
+            u16::try_from(offset)
+                .ok()
+                .and_then(|offset| self.index_at_offset(offset))
+                .unwrap_or(last.index)
+        };
+        if let Some(drag) = &self.drag {
+            self.marked.clone_from(&drag.marked);
+        }
+        self.marked.extend(anchor.min(index)..=anchor.max(index));
+        self.select(Some(index));
+        self.invalidate_item_offsets();
+    }
+
+    /// Ends the drag selection, e.g. when the mouse button is released. The
+    /// marked items are kept.
+    pub fn drag_end(&mut self) {
+        self.drag = None;
+    }
+
+    /// Scrolls the viewport by `delta` items, e.g. on a mouse wheel event.
+    /// Positive values scroll towards the end of the list.
+    ///
+    /// The selection follows the viewport like in pagers such as `less`: if the
+    /// selected item would scroll out of view, it is moved to the first or last
+    /// item that remains visible. The viewport is computed from the last
+    /// render, so this has no effect before the list was rendered once.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 3);
+    /// let mut state = ListState::default();
+    /// state.select(Some(0));
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 10).render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// state.scroll_by(2);
+    /// assert_eq!(state.scroll_offset_index(), 2);
+    /// assert_eq!(state.selected, Some(2));
+    /// ```
+    pub fn scroll_by(&mut self, delta: isize) {
+        let (Some(first), Some(last)) = (self.item_spans.first(), self.item_spans.last()) else {
+            return;
+        };
+        let visible = last.index.saturating_sub(first.index);
+        let max_offset = self.num_elements.saturating_sub(visible + 1);
+        let offset = self
+            .view_state
+            .offset
+            .saturating_add_signed(delta)
+            .min(max_offset);
+        if offset == self.view_state.offset {
+            return;
+        }
+        self.view_state = ViewState {
+            offset,
+            first_truncated: 0,
+        };
+        match self.selected {
+            Some(selected) => self.select(Some(selected.clamp(offset, offset + visible))),
+            None => self.offset_anchored = true,
+        }
+    }
+
+    /// Scrolls the viewport such that the item at `index` becomes the first
+    /// visible item, without changing the selection, e.g. to jump to a position
+    /// in a read-only view.
+    ///
+    /// The viewport keeps its offset across renders as long as no item is
+    /// selected. If an item is selected, the next render scrolls it back into
+    /// view like any other change of the offset.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 3);
+    /// let mut state = ListState::default();
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let list = ListView::new(builder, 10);
+    ///
+    /// state.scroll_to(4);
+    /// list.render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert_eq!(state.scroll_offset_index(), 4);
+    /// assert_eq!(state.selected, None);
+    /// ```
+    pub fn scroll_to(&mut self, index: usize) {
+        self.view_state = ViewState {
+            offset: index,
+            first_truncated: 0,
+        };
+        self.offset_anchored = true;
+    }
+
+    /// Records how far and in which direction the viewport moved since the
+    /// last render.
+    pub(crate) fn track_scroll_motion(&mut self) {
+        let offset = self.view_state.offset;
+        if let Some(previous) = self.rendered_offset.replace(offset) {
+            self.scroll_velocity = offset.abs_diff(previous);
+            match offset.cmp(&previous) {
+                Ordering::Greater => self.scroll_direction = Some(Step::Next),
+                Ordering::Less => self.scroll_direction = Some(Step::Previous),
+                Ordering::Equal => {}
+            }
+        }
+    }
+
+    /// Returns the range of items that are likely to become visible soon, e.g.
+    /// for data layers that prefetch items, see [`ListState::mark_loaded`].
+    ///
+    /// The range contains the visible items of the last render and extends by
+    /// `lookahead_cells` in the direction in which the viewport moved most
+    /// recently, or in which the selection was moved if the viewport did not
+    /// move yet. The faster the viewport moved during the last render, the
+    /// further the range extends. If the direction is unknown, the range
+    /// extends in both directions. Items outside of the viewport are assumed
+    /// to have the average size of the visible ones.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 4);
+    /// let mut state = ListState::default();
+    /// for selected in [0, 5] {
+    ///     state.select(Some(selected));
+    ///     let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 2));
+    ///     ListView::new(builder, 100).render(area, &mut Buffer::empty(area), &mut state);
+    /// }
+    ///
+    /// // The viewport moved down by 4 items, so the prediction extends by
+    /// // 4 items plus the 3 items that fit into 6 cells of lookahead.
+    /// assert_eq!(state.predicted_range(6), 4..13);
+    /// ```
+    #[must_use]
+    pub fn predicted_range(&self, lookahead_cells: u16) -> Range<usize> {
+        let (Some(first), Some(last)) = (self.item_spans.first(), self.item_spans.last()) else {
+            return 0..0;
+        };
+        let (first, last) = (first.index.min(last.index), first.index.max(last.index));
+        let visible_cells: usize = self
+            .item_spans
+            .iter()
+            .map(|span| usize::from(span.size))
+            .sum();
+        let average_size = (visible_cells / self.item_spans.len()).max(1);
+        let lookahead = usize::from(lookahead_cells).div_ceil(average_size) + self.scroll_velocity;
+        let (before, after) = match self.scroll_direction.or(self.last_step) {
+            Some(Step::Next) => (0, lookahead),
+            Some(Step::Previous) => (lookahead, 0),
+            None => (lookahead, lookahead),
+        };
+        first.saturating_sub(before)..(last + 1).saturating_add(after).min(self.num_elements)
+    }
+
+    /// Returns false if the last render deferred visible items because the
+    /// build budget was exhausted, see [`ListView::build_budget`]. In this case
+    /// the application should render another frame soon.
+    ///
+    /// [`ListView::build_budget`]: crate::ListView::build_budget
+    #[must_use]
+    pub fn render_complete(&self) -> bool {
+        self.render_complete
+    }
+
+    /// Registers `key` as the shortcut of the item at `index`, e.g. to jump to
+    /// the item with a single key press. A key that was already registered is
+    /// reassigned. See [`ListView::shortcut_hints`] to render the shortcuts next
+    /// to the items.
+    ///
+    /// [`ListView::shortcut_hints`]: crate::ListView::shortcut_hints
+    pub fn set_shortcut(&mut self, key: char, index: usize) {
+        self.shortcuts.insert(key, index);
+    }
+
+    /// Registers the keys `1` to `9` as the shortcuts of the first nine items.
+    ///
+    /// # Example
+    /// ```
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.set_number_shortcuts();
+    /// assert_eq!(state.shortcut_for(0), Some('1'));
+    /// assert_eq!(state.shortcut_for(8), Some('9'));
+    /// ```
+    pub fn set_number_shortcuts(&mut self) {
+        for (index, key) in ('1'..='9').enumerate() {
+            self.set_shortcut(key, index);
+        }
+    }
+
+    /// Removes the shortcut `key`.
+    pub fn remove_shortcut(&mut self, key: char) {
+        self.shortcuts.remove(&key);
+    }
+
+    /// Removes all shortcuts.
+    pub fn clear_shortcuts(&mut self) {
+        self.shortcuts.clear();
+    }
+
+    /// Returns the shortcut of the item at `index`. If several keys are
+    /// registered for the item, the smallest one is returned.
+    #[must_use]
+    pub fn shortcut_for(&self, index: usize) -> Option<char> {
+        self.shortcuts
+            .iter()
+            .find(|(_, &i)| i == index)
+            .map(|(&key, _)| key)
+    }
+
+    /// Handles a key press: if `key` is the shortcut of an item, the item is
+    /// selected and its index is returned, such that the application can
+    /// activate it. Returns `None` if `key` is not a shortcut, the item does
+    /// not exist or an item is being edited.
+    ///
+    /// # Example
+    /// ```
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.set_shortcut('a', 0);
+    /// assert_eq!(state.activate_shortcut('a'), Some(0));
+    /// assert_eq!(state.selected, Some(0));
+    /// assert_eq!(state.activate_shortcut('b'), None);
+    /// ```
+    pub fn activate_shortcut(&mut self, key: char) -> Option<usize> {
+        if self.editing.is_some() {
+            return None;
+        }
+        let index = *self.shortcuts.get(&key)?;
+        if self.num_elements > 0 && index >= self.num_elements {
+            return None;
+        }
+        self.select(Some(index));
+        Some(index)
+    }
+
+    /// Returns the signed offset of `position` from the start of the viewport
+    /// along the main axis during the last render.
+    fn main_axis_offset(&self, position: Position) -> i32 {
+        let position = Rect::new(position.x, position.y, 0, 0);
+        i32::from(self.scroll_axis.main_pos(position))
+            - i32::from(self.scroll_axis.main_pos(self.inner_area))
+    }
+
+    /// Returns the index of the first item currently displayed on the screen.
+    #[must_use]
+    pub fn scroll_offset_index(&self) -> usize {
+        self.view_state.offset
+    }
+
+    /// Returns the state of a [`Scrollbar`] that tracks the selected item of
+    /// the list, see [`ScrollAxis::scrollbar`].
+    ///
+    /// [`Scrollbar`]: ratatui::widgets::Scrollbar
+    #[must_use]
+    pub fn scrollbar_state(&self) -> ScrollbarState {
+        ScrollbarState::new(self.num_elements).position(self.selected.unwrap_or(0))
+    }
+
+    /// Returns the state of the built-in scrollbar, whose thumb is mirrored if
+    /// the list starts from the right edge.
+    pub(crate) fn built_in_scrollbar_state(&self) -> ScrollbarState {
+        let position = self.selected.unwrap_or(0);
+        let position = if self.scrollbar_mirrored {
+            self.num_elements.saturating_sub(1).saturating_sub(position)
+        } else {
+            position
+        };
+        ScrollbarState::new(self.num_elements).position(position)
+    }
+
+    /// Returns the position of the viewport during the last render.
+    #[must_use]
+    pub fn view_state(&self) -> ViewState {
+        self.view_state
+    }
+
+    /// Sets the position of the viewport, e.g. to restore a position that was
+    /// taken from another state with [`ListState::view_state`]. The position is
+    /// adjusted on the next render such that the selected item is visible.
+    pub fn set_view_state(&mut self, view_state: ViewState) {
+        self.view_state = view_state;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        buffer::Buffer,
+        text::Line,
+        widgets::{Block, StatefulWidget},
+    };
+
+    use super::*;
+    use crate::{ListBuilder, ListView};
+
+    #[test]
+    fn take_wrapped() {
+        // given
+        let mut state = ListState::default();
+        state.set_num_elements(3);
+        state.select(Some(1));
+
+        // when
+        state.next();
+
+        // then
+        assert_eq!(state.take_wrapped(), None);
+
+        // when
+        state.next();
+
+        // then
+        assert_eq!(state.selected, Some(0));
+        assert_eq!(state.take_wrapped(), Some(Wrapped::ToFirst));
+        assert_eq!(state.take_wrapped(), None);
+
+        // when
+        state.previous();
+
+        // then
+        assert_eq!(state.take_wrapped(), Some(Wrapped::ToLast));
+    }
+
+    #[test]
+    fn drag_scrolls_at_viewport_edge() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 10).render(area, &mut Buffer::empty(area), state);
+        };
+        render(&mut state);
+        state.set_marked(8, true);
+
+        // when
+        state.drag_start(Position::new(0, 1));
+        state.drag_to(Position::new(0, 2));
+        render(&mut state);
+        state.drag_to(Position::new(0, 2));
+        render(&mut state);
+        state.drag_end();
+
+        // then
+        assert_eq!(state.selected, Some(4));
+        assert_eq!(state.scroll_offset_index(), 2);
+        assert_eq!(
+            state.marked().iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 8]
+        );
+
+        // when
+        state.drag_start(Position::new(0, 2));
+        state.drag_to(Position::new(0, 1));
+
+        // then
+        assert_eq!(state.selected, Some(3));
+        assert_eq!(
+            state.marked().iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 8]
+        );
+    }
+
+    #[test]
+    fn item_at_position_with_truncated_items() {
+        // given
+        let area = Rect::new(0, 0, 3, 6);
+        let mut state = ListState::default();
+        state.select(Some(2));
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 3));
+        ListView::new(builder, 4).block(Block::bordered()).render(
+            area,
+            &mut Buffer::empty(area),
+            &mut state,
+        );
+
+        // when
+        let hits: Vec<_> = (0..6)
+            .map(|y| state.item_at_position(Position::new(1, y)))
+            .collect();
+
+        // then
+        assert_eq!(hits, vec![None, Some(1), Some(2), Some(2), Some(2), None]);
+    }
+
+    #[test]
+    fn predicted_range_follows_navigation() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 10).render(area, &mut Buffer::empty(area), state);
+        };
+        state.select(Some(4));
+        render(&mut state);
+        let unknown = state.predicted_range(2);
+
+        // when
+        state.previous();
+        render(&mut state);
+
+        // then
+        assert_eq!(unknown, 0..7);
+        assert_eq!(state.scroll_offset_index(), 2);
+        assert_eq!(state.predicted_range(2), 0..5);
+    }
+
+    #[test]
+    fn scroll_by_moves_selection_into_view() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 10).render(area, &mut Buffer::empty(area), state);
+        };
+        state.select(Some(1));
+        render(&mut state);
+
+        // when
+        state.scroll_by(1);
+        render(&mut state);
+
+        // then
+        assert_eq!(state.scroll_offset_index(), 1);
+        assert_eq!(state.selected, Some(1));
+
+        // when
+        state.scroll_by(100);
+        render(&mut state);
+
+        // then
+        assert_eq!(state.scroll_offset_index(), 7);
+        assert_eq!(state.selected, Some(7));
+
+        // when
+        state.scroll_by(-3);
+        render(&mut state);
+
+        // then
+        assert_eq!(state.scroll_offset_index(), 4);
+        assert_eq!(state.selected, Some(6));
+    }
+
+    #[test]
+    fn scroll_by_without_selection() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 10).render(area, &mut Buffer::empty(area), state);
+        };
+        render(&mut state);
+
+        // when
+        state.scroll_by(3);
+        render(&mut state);
+
+        // then
+        assert_eq!(state.scroll_offset_index(), 3);
+        assert_eq!(state.selected, None);
+    }
+
+    #[test]
+    fn ensure_selected_visible() {
+        // given
+        let area = Rect::new(0, 0, 3, 4);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 2));
+            ListView::new(builder, 10).render(area, &mut Buffer::empty(area), state);
+        };
+        state.select(Some(4));
+        render(&mut state);
+
+        // when
+        state.select(Some(1));
+        state.ensure_selected_visible();
+
+        // then
+        assert_eq!(
+            state.view_state(),
+            ViewState {
+                offset: 1,
+                first_truncated: 0
+            }
+        );
+
+        // when
+        render(&mut state);
+        state.select(Some(6));
+        state.ensure_selected_visible();
+
+        // then
+        assert_eq!(
+            state.view_state(),
+            ViewState {
+                offset: 5,
+                first_truncated: 0
+            }
+        );
+        render(&mut state);
+        assert_eq!(
+            state.view_state(),
+            ViewState {
+                offset: 5,
+                first_truncated: 0
+            }
+        );
+    }
+
+    #[test]
+    fn ensure_selected_visible_with_mixed_sizes() {
+        // given
+        let area = Rect::new(0, 0, 3, 4);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| {
+                let size = if c.index % 3 == 2 { 3 } else { 1 };
+                (Line::from(format!("{}", c.index)), size)
+            });
+            ListView::new(builder, 10).render(area, &mut Buffer::empty(area), state);
+        };
+        render(&mut state);
+
+        // when
+        state.select(Some(2));
+        state.ensure_selected_visible();
+
+        // then
+        let expected = ViewState {
+            offset: 1,
+            first_truncated: 0,
+        };
+        assert_eq!(state.view_state(), expected);
+        render(&mut state);
+        assert_eq!(state.view_state(), expected);
+    }
+
+    #[test]
+    fn home_end_and_pages() {
+        for scroll_axis in [ScrollAxis::Vertical, ScrollAxis::Horizontal] {
+            // given
+            let area = Rect::new(0, 0, 3, 3);
+            let mut state = ListState::default();
+            let render = |state: &mut ListState| {
+                let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+                let list = ListView::new(builder, 10).scroll_axis(scroll_axis);
+                list.render(area, &mut Buffer::empty(area), state);
+            };
+            render(&mut state);
+
+            // when
+            state.select_last();
+            render(&mut state);
+
+            // then
+            assert_eq!(state.selected, Some(9));
+            assert_eq!(state.scroll_offset_index(), 7);
+
+            // when
+            state.previous_page();
+            render(&mut state);
+
+            // then
+            assert_eq!(state.selected, Some(7));
+
+            // when
+            state.select_first();
+            render(&mut state);
+            state.next_page();
+
+            // then
+            assert_eq!(state.selected, Some(2));
+        }
+    }
+
+    #[test]
+    fn select_relative_saturates_without_infinite_scrolling() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+        let list = ListView::new(builder, 10).infinite_scrolling(false);
+        list.render(area, &mut Buffer::empty(area), &mut state);
+
+        // when
+        state.select_relative(3);
+
+        // then
+        assert_eq!(state.selected, Some(0));
+
+        // when
+        state.select_relative(25);
+
+        // then
+        assert_eq!(state.selected, Some(9));
+
+        // when
+        state.select_relative(-4);
+        state.select_relative(isize::MIN);
+
+        // then
+        assert_eq!(state.selected, Some(0));
+        assert_eq!(state.take_wrapped(), None);
+    }
+
+    #[test]
+    fn scroll_to_keeps_offset_without_selection() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let mut render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 10).render(area, &mut buf, state);
+        };
+
+        // when
+        state.scroll_to(20);
+        render(&mut state);
+
+        // then
+        assert_eq!(state.scroll_offset_index(), 9);
+
+        // when
+        state.scroll_to(2);
+        render(&mut state);
+        render(&mut state);
+
+        // then
+        assert_eq!(state.scroll_offset_index(), 2);
+        assert_eq!(state.selected, None);
+        assert_eq!(buf, Buffer::with_lines(["2  ", "3  ", "4  "]));
+    }
+
+    #[test]
+    fn pages_by_item_heights() {
+        // given
+        let area = Rect::new(0, 0, 3, 6);
+        let mut state = ListState::default();
+        let builds = std::cell::RefCell::new(0);
+        let render = |state: &mut ListState| {
+            let builder = ListBuilder::new(|c| {
+                *builds.borrow_mut() += 1;
+                let size = if c.index == 0 { 4 } else { 1 };
+                (Line::from(format!("{}", c.index)), size)
+            });
+            ListView::new(builder, 10).render(area, &mut Buffer::empty(area), state);
+        };
+        state.select(Some(1));
+        render(&mut state);
+
+        // when
+        builds.replace(0);
+        state.next_page();
+
+        // then
+        // The items behind the viewport are assumed to have the average size.
+        assert_eq!(state.selected, Some(4));
+        assert_eq!(*builds.borrow(), 0);
+
+        // when
+        render(&mut state);
+        state.previous_page();
+
+        // then
+        assert_eq!(state.selected, Some(1));
+
+        // when
+        state.previous_page();
+
+        // then
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn shared_state_with_different_item_counts() {
+        // given
+        let mut state = ListState::default();
+
+        // when
+        for num_elements in [3, 5, 3, 5, 3] {
+            state.set_num_elements(num_elements);
+        }
+
+        // then
+        assert_eq!(state.num_elements_flip_flops(), 0);
+
+        // when
+        for num_elements in [5, 3] {
+            state.set_num_elements(num_elements);
+        }
+
+        // then
+        assert_eq!(state.num_elements_flip_flops(), 2);
+    }
+
+    #[test]
+    fn tick_auto_advances() {
+        // given
+        let mut state = ListState::default();
+        state.set_num_elements(3);
+        state.set_auto_advance(Some(Duration::from_millis(100)));
+
+        // when
+        state.tick(Duration::from_millis(50));
+
+        // then
+        assert_eq!(state.selected, None);
+
+        // when
+        state.tick(Duration::from_millis(260));
+
+        // then
+        assert_eq!(state.selected, Some(2));
+
+        // when
+        state.tick(Duration::from_millis(90));
+
+        // then
+        assert_eq!(state.selected, Some(0));
+        assert_eq!(state.take_wrapped(), Some(Wrapped::ToFirst));
+    }
+
+    #[test]
+    fn notify_prepended_keeps_content() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState, item_count: usize| {
+            let mut buf = Buffer::empty(area);
+            let builder =
+                ListBuilder::new(|c| (Line::from(format!("{}", item_count - c.index)), 1));
+            ListView::new(builder, item_count).render(area, &mut buf, state);
+            buf
+        };
+        state.select(Some(3));
+        render(&mut state, 10);
+        state.set_marked(4, true);
+
+        // when
+        state.notify_prepended(2);
+        let buf = render(&mut state, 12);
+
+        // then
+        assert_eq!(state.selected, Some(5));
+        assert!(state.is_marked(6));
+        assert_eq!(buf, Buffer::with_lines(["9  ", "8  ", "7  "]));
+    }
+
+    #[test]
+    fn mark_loaded_merges_ranges() {
+        // given
+        let mut state = ListState::default();
+
+        // when
+        state.mark_loaded(5..8);
+        state.mark_loaded(0..2);
+        state.mark_loaded(2..5);
+        state.mark_loaded(10..12);
+
+        // then
+        assert_eq!(state.loaded, vec![0..8, 10..12]);
     }
 }
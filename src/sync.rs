@@ -0,0 +1,163 @@
+use std::sync::mpsc::Sender;
+
+/// The position of the viewport of a list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollPosition {
+    /// The index of the first item displayed on the viewport.
+    pub offset: usize,
+
+    /// The index of the selected item, if any.
+    pub selected: Option<usize>,
+
+    /// The total number of items in the list.
+    pub item_count: usize,
+}
+
+/// The scroll progress of a list in cells, see [`ListView::on_scroll_progress`].
+///
+/// [`ListView::on_scroll_progress`]: crate::ListView::on_scroll_progress
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ScrollProgress {
+    /// The number of cells along the main axis that are scrolled out of view
+    /// at the start of the list.
+    pub offset_cells: usize,
+
+    /// The total size of all items along the main axis in cells.
+    pub total_cells: usize,
+
+    /// The size of the viewport along the main axis in cells.
+    pub viewport_cells: u16,
+}
+
+/// An adapter that synchronizes the viewport position of a list with an
+/// external model, e.g. to mirror the list position into a chart cursor.
+///
+/// The adapter is attached to a list with [`ListView::scroll_sync`]. It is
+/// implemented for closures and for [`Sender`]. To keep ownership of a custom
+/// adapter across frames, implement the trait for a mutable reference to it.
+///
+/// [`ListView::scroll_sync`]: crate::ListView::scroll_sync
+pub trait ScrollSync {
+    /// Publishes the viewport position. This is called after the layout
+    /// whenever the position has changed since the last render.
+    fn publish(&mut self, position: ScrollPosition);
+
+    /// Polled before the layout. Returns the index of the item that should
+    /// be selected, allowing the external model to drive the list.
+    ///
+    /// Returns `None` by default.
+    fn poll(&mut self) -> Option<usize> {
+        None
+    }
+}
+
+impl<F: FnMut(ScrollPosition)> ScrollSync for F {
+    fn publish(&mut self, position: ScrollPosition) {
+        self(position);
+    }
+}
+
+impl ScrollSync for Sender<ScrollPosition> {
+    fn publish(&mut self, position: ScrollPosition) {
+        // The receiver might have been dropped, which is fine.
+        let _ = self.send(position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+
+    use ratatui::{prelude::*, widgets::StatefulWidget};
+
+    use super::*;
+    use crate::{ListBuilder, ListState, ListView};
+
+    struct Driver(Option<usize>, Vec<ScrollPosition>);
+
+    impl ScrollSync for &mut Driver {
+        fn publish(&mut self, position: ScrollPosition) {
+            self.1.push(position);
+        }
+
+        fn poll(&mut self) -> Option<usize> {
+            self.0.take()
+        }
+    }
+
+    fn render<S: ScrollSync>(state: &mut ListState, sync: S) {
+        let area = Rect::new(0, 0, 5, 2);
+        let builder = ListBuilder::new(|_| (Line::from("Item"), 1));
+        let list = ListView::new(builder, 4).scroll_sync(sync);
+        list.render(area, &mut Buffer::empty(area), state);
+    }
+
+    #[test]
+    fn publish_on_change() {
+        // given
+        let mut state = ListState::default();
+        let (sender, receiver) = channel();
+
+        // when
+        render(&mut state, sender.clone());
+        render(&mut state, sender.clone());
+        state.select(Some(3));
+        render(&mut state, sender.clone());
+
+        // then
+        let positions: Vec<_> = receiver.try_iter().collect();
+        assert_eq!(
+            positions,
+            vec![
+                ScrollPosition {
+                    offset: 0,
+                    selected: None,
+                    item_count: 4
+                },
+                ScrollPosition {
+                    offset: 2,
+                    selected: Some(3),
+                    item_count: 4
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn driven_by_poll() {
+        // given
+        let mut state = ListState::default();
+        let mut driver = Driver(Some(2), Vec::new());
+
+        // when
+        render(&mut state, &mut driver);
+
+        // then
+        assert_eq!(state.selected, Some(2));
+        assert_eq!(driver.1[0].offset, 1);
+    }
+
+    #[test]
+    fn scroll_progress_with_truncation() {
+        // given
+        let area = Rect::new(0, 0, 3, 4);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let mut progress = Vec::new();
+        let builder = ListBuilder::new(|_| (Line::from("x"), 3));
+        let list = ListView::new(builder, 3).on_scroll_progress(|p| progress.push(p));
+
+        // when
+        list.render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        assert_eq!(
+            progress,
+            vec![ScrollProgress {
+                offset_cells: 2,
+                total_cells: 9,
+                viewport_cells: 4,
+            }]
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Conversions between the `u16` cell sizes of the terminal and the `usize`
+//! indices and counts of the list.
+//!
+//! Sizes on the terminal are `u16`, while item indices, item counts and
+//! cumulative offsets are `usize`. Casting with `as` silently wraps values
+//! that do not fit, e.g. an item count of `65_536` becomes `0`. The helpers in
+//! this module saturate instead, such that out of range values end up at the
+//! nearest representable bound.
+//!
+//! # Example
+//! ```
+//! use tui_widget_list::units::{saturating_u16, scale};
+//!
+//! assert_eq!(saturating_u16(70_000), u16::MAX);
+//! assert_eq!(scale(10, 1, 3), 3);
+//! ```
+
+/// Converts a count or an offset to a cell size, saturating at `u16::MAX`.
+#[must_use]
+pub fn saturating_u16(value: usize) -> u16 {
+    u16::try_from(value).unwrap_or(u16::MAX)
+}
+
+/// Converts a signed size to a cell size, clamping negative values to zero
+/// and large values to `u16::MAX`.
+#[must_use]
+pub fn clamp_u16(value: i32) -> u16 {
+    u16::try_from(value.max(0)).unwrap_or(u16::MAX)
+}
+
+/// Converts a cell size to an index or a count. This conversion is lossless.
+#[must_use]
+pub fn to_usize(value: u16) -> usize {
+    usize::from(value)
+}
+
+/// Scales a cell size by `numerator / denominator`, rounding down and
+/// saturating at `u16::MAX`. A denominator of zero is treated as one.
+#[must_use]
+pub fn scale(value: u16, numerator: u32, denominator: u32) -> u16 {
+    let scaled = u64::from(value) * u64::from(numerator) / u64::from(denominator.max(1));
+    u16::try_from(scaled).unwrap_or(u16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions_saturate() {
+        // given
+        let large = usize::from(u16::MAX) + 1;
+
+        // when
+        let converted = (saturating_u16(large), clamp_u16(-1), clamp_u16(i32::MAX));
+
+        // then
+        assert_eq!(converted, (u16::MAX, 0, u16::MAX));
+        assert_eq!(scale(u16::MAX, 3, 2), u16::MAX);
+        assert_eq!(scale(7, 1, 0), 7);
+    }
+}
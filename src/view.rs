@@ -1,11 +1,28 @@
 use ratatui::{
     buffer::Buffer,
-    layout::{Position, Rect},
-    style::{Style, Styled},
-    widgets::{block::BlockExt, Block, StatefulWidget, Widget},
+    layout::{Constraint, Position, Rect, Size},
+    style::{Color, Modifier, Style, Styled},
+    text::{Line, Span, Text},
+    widgets::{
+        block::BlockExt, Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation,
+        StatefulWidget, Widget, Wrap,
+    },
 };
 
-use crate::{utils::layout_on_viewport, ListState};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeSet, HashMap},
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+    layout::{layout_on_viewport, SizeCacheKey, Truncation, ViewportElement},
+    popup_area,
+    state::{ExpansionState, ItemSpan, ItemStyle},
+    units, ColorScale, Easing, ItemCache, ListState, ScrollBehavior, ScrollContext, ScrollPosition,
+    ScrollProgress, ScrollSync, ScrollbarThumbState, ViewState,
+};
 
 /// A struct representing a list view.
 /// The widget displays a scrollable list of items.
@@ -32,12 +49,142 @@ pub struct ListView<'a, T> {
     /// Whether infinite scrolling is enabled or not.
     /// Disabled by default.
     pub(crate) infinite_scrolling: bool,
+
+    /// Whether the items should be stretched to fill the viewport
+    /// if they all fit onto it. Disabled by default.
+    pub(crate) fit_items: bool,
+
+    /// An optional gutter that is rendered along the cross axis.
+    pub(crate) gutter: Option<Gutter<'a>>,
+
+    /// Whether the shortcut keys of the items are rendered at the start of the gutter.
+    pub(crate) shortcut_hints: bool,
+
+    /// The maximum number of items that are built for the first time per render.
+    pub(crate) build_budget: Option<usize>,
+
+    /// An optional header that is rendered above the items.
+    pub(crate) header: Option<Header<'a>>,
+
+    /// Renders the editor in place of the edited item.
+    pub(crate) editor: Option<Box<EditorClosure<'a>>>,
+
+    /// The number of renders over which an inline expansion is animated.
+    pub(crate) expansion_frames: u16,
+
+    /// The easing curve of the expansion animation.
+    pub(crate) expansion_easing: Easing,
+
+    /// The minimum visible size of a truncated last item.
+    pub(crate) min_partial_size: u16,
+
+    /// The placement of the items if they do not fill the viewport.
+    pub(crate) main_axis_alignment: MainAxisAlignment,
+
+    /// Whether horizontal lists start from the right edge.
+    pub(crate) right_to_left: bool,
+
+    /// The style of the cells that truncated items do not render themselves.
+    pub(crate) base_style_under_truncation: Option<Style>,
+
+    /// The style that is patched over the list, except for the selected item.
+    pub(crate) dim_style: Option<Style>,
+
+    /// The style that is patched over the selected item and its gutter.
+    pub(crate) selection_band: Option<Style>,
+
+    /// Whether the list has the input focus.
+    pub(crate) focused: bool,
+
+    /// The style that is patched over the base style while the list is unfocused.
+    pub(crate) unfocused_style: Option<Style>,
+
+    /// The selection band while the list is unfocused.
+    pub(crate) unfocused_selection_band: Option<Style>,
+
+    /// Whether the border of the block on the side of the scrollbar is omitted.
+    pub(crate) direction_aware_block: bool,
+
+    /// Whether a scrollbar is rendered inside the block.
+    pub(crate) scrollbar: bool,
+
+    /// The styles of the scrollbar thumb while it is idle, hovered and dragged.
+    pub(crate) scrollbar_thumb_styles: [Option<Style>; 3],
+
+    /// The separator between adjacent items, see [`ListView::separator`].
+    pub(crate) separator: Option<Span<'a>>,
+
+    /// Renders a diagnostic if all visible items have a size of zero.
+    pub(crate) zero_size_diagnostic: Option<Box<DiagnosticClosure<'a>>>,
+
+    /// Tints the background of the items by a value.
+    pub(crate) heatmap: Option<(Box<HeatmapClosure<'a>>, ColorScale)>,
+
+    /// The number of items around the selection that are always built.
+    pub(crate) context_items: usize,
+
+    /// Renders the placeholders of items that are not loaded yet.
+    pub(crate) placeholder: Option<Box<PlaceholderClosure<'a>>>,
+
+    /// Decides during rendering whether the selection may change from one
+    /// item to another.
+    pub(crate) selection_filter: Option<Box<SelectionFilterClosure<'a>>>,
+
+    /// An optional cache for the built items.
+    pub(crate) item_cache: Option<Box<dyn ItemCache<T> + 'a>>,
+
+    /// An optional policy that decides which item is shown first on the viewport.
+    pub(crate) scroll_behavior: Option<Box<dyn ScrollBehavior + 'a>>,
+
+    /// An optional adapter that synchronizes the viewport position with an external model.
+    pub(crate) scroll_sync: Option<Box<dyn ScrollSync + 'a>>,
+
+    /// An optional observer of the scroll progress in cells.
+    pub(crate) scroll_progress: Option<Box<ScrollProgressClosure<'a>>>,
+}
+
+/// A type alias for the header render closure.
+type HeaderClosure<'a> = dyn FnOnce(Rect, &mut Buffer) + 'a;
+
+/// A widget rendered inside the block above the items. It is never scrolled
+/// and not selectable.
+pub(crate) struct Header<'a> {
+    /// The height of the header.
+    pub(crate) height: u16,
+
+    /// Renders the header widget.
+    pub(crate) render: Box<HeaderClosure<'a>>,
+}
+
+/// A type alias for the scroll progress observer.
+type ScrollProgressClosure<'a> = dyn FnMut(ScrollProgress) + 'a;
+
+/// A type alias for the heatmap value closure.
+type HeatmapClosure<'a> = dyn Fn(usize) -> f32 + 'a;
+
+/// A type alias for the editor render closure.
+type EditorClosure<'a> = dyn FnOnce(Rect, &mut Buffer) + 'a;
+
+/// A type alias for the zero size diagnostic render closure.
+type DiagnosticClosure<'a> = dyn FnOnce(Rect, &mut Buffer) + 'a;
+
+/// A type alias for the gutter closure.
+type GutterClosure<'a> = dyn Fn(usize, bool) -> Line<'a> + 'a;
+
+/// A fixed-size column (or row for horizontal lists) rendered next to the items.
+pub(crate) struct Gutter<'a> {
+    /// The size of the gutter along the cross axis.
+    pub(crate) width: u16,
+
+    /// Constructs the gutter content from the item index and its selection status.
+    pub(crate) closure: Box<GutterClosure<'a>>,
 }
 
 impl<'a, T> ListView<'a, T> {
     /// Creates a new `ListView` with a builder an item count.
     #[must_use]
-    pub fn new(builder: ListBuilder<'a, T>, item_count: usize) -> Self {
+    pub fn new(mut builder: ListBuilder<'a, T>, item_count: usize) -> Self {
+        builder.item_count = item_count;
         Self {
             builder,
             item_count,
@@ -46,6 +193,36 @@ impl<'a, T> ListView<'a, T> {
             block: None,
             scroll_padding: 0,
             infinite_scrolling: true,
+            fit_items: false,
+            gutter: None,
+            shortcut_hints: false,
+            build_budget: None,
+            header: None,
+            editor: None,
+            expansion_frames: 0,
+            expansion_easing: Easing::Linear,
+            min_partial_size: 0,
+            main_axis_alignment: MainAxisAlignment::Start,
+            right_to_left: false,
+            base_style_under_truncation: None,
+            dim_style: None,
+            selection_band: None,
+            focused: true,
+            unfocused_style: None,
+            unfocused_selection_band: None,
+            direction_aware_block: false,
+            scrollbar: false,
+            scrollbar_thumb_styles: [None; 3],
+            separator: None,
+            zero_size_diagnostic: None,
+            heatmap: None,
+            context_items: 0,
+            placeholder: None,
+            selection_filter: None,
+            item_cache: None,
+            scroll_behavior: None,
+            scroll_sync: None,
+            scroll_progress: None,
         }
     }
 
@@ -68,6 +245,86 @@ impl<'a, T> ListView<'a, T> {
         self
     }
 
+    /// Draws the borders of the block depending on the scroll axis: all borders
+    /// except the one on the side of the scrollbar of [`ScrollAxis::scrollbar`],
+    /// i.e. the right border of vertical lists and the bottom border of
+    /// horizontal lists. The scrollbar takes the place of the border, which
+    /// avoids double lines and saves a column or row.
+    ///
+    /// The borders set on the block are replaced.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::{Block, StatefulWidget}};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut state = ListState::default();
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let list = ListView::new(builder, 20)
+    ///     .block(Block::bordered())
+    ///     .direction_aware_block(true);
+    /// let (scrollbar, list_area) = list.scroll_axis.scrollbar(area);
+    ///
+    /// list.render(list_area, &mut buf, &mut state);
+    /// scrollbar.render(area, &mut buf, &mut state.scrollbar_state());
+    /// ```
+    #[must_use]
+    pub fn direction_aware_block(mut self, direction_aware_block: bool) -> Self {
+        self.direction_aware_block = direction_aware_block;
+        self
+    }
+
+    /// Renders a scrollbar that tracks the selected item inside the block, on
+    /// the right of vertical lists and at the bottom of horizontal lists. The
+    /// thumb can be dragged with the mouse, see [`ListState::handle_mouse_event`].
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 3, 4);
+    /// let mut buf = Buffer::empty(area);
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 8).scrollbar(true).render(area, &mut buf, &mut ListState::default());
+    ///
+    /// assert_eq!(buf, Buffer::with_lines(["0 █", "1 ║", "2 ║", "3 ║"]));
+    /// ```
+    #[must_use]
+    pub fn scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// Sets the style of the thumb of the built-in scrollbar, see
+    /// [`ListView::scrollbar`].
+    #[must_use]
+    pub fn scrollbar_thumb_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.scrollbar_thumb_styles[0] = Some(style.into());
+        self
+    }
+
+    /// Sets the style of the thumb of the built-in scrollbar while the mouse
+    /// hovers over the scrollbar, see [`ListState::scrollbar_thumb_state`].
+    /// Defaults to the [thumb style](ListView::scrollbar_thumb_style).
+    #[must_use]
+    pub fn scrollbar_thumb_hover_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.scrollbar_thumb_styles[1] = Some(style.into());
+        self
+    }
+
+    /// Sets the style of the thumb of the built-in scrollbar while it is
+    /// dragged, see [`ListState::scrollbar_thumb_state`]. Defaults to the
+    /// [thumb style](ListView::scrollbar_thumb_style).
+    #[must_use]
+    pub fn scrollbar_thumb_drag_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.scrollbar_thumb_styles[2] = Some(style.into());
+        self
+    }
+
     /// Set the base style of the List.
     #[must_use]
     pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
@@ -95,346 +352,4041 @@ impl<'a, T> ListView<'a, T> {
         self.infinite_scrolling = infinite_scrolling;
         self
     }
-}
 
-impl<T> Styled for ListView<'_, T> {
-    type Item = Self;
-
-    fn style(&self) -> Style {
-        self.style
+    /// Expands the selected item inline with a detail widget.
+    ///
+    /// The closure receives the index of the selected item and returns the detail
+    /// widget and its size along the main axis. The detail widget is inserted right
+    /// after the selected item and is laid out and scrolled as part of the list,
+    /// similar to an accordion.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("Item {}", context.index)), 1));
+    /// let list = ListView::new(builder, 10)
+    ///     .expanded(|index| (Line::from(format!("Details of item {index}")), 1));
+    /// ```
+    #[must_use]
+    pub fn expanded<F, D>(mut self, expansion: F) -> Self
+    where
+        F: Fn(usize) -> (D, u16) + 'a,
+        D: Widget + 'a,
+    {
+        self.builder.expansion = Some(Box::new(move |index, target| {
+            let (widget, main_axis_size) = expansion(index);
+            if let Some((area, buf)) = target {
+                widget.render(area, buf);
+            }
+            main_axis_size
+        }));
+        self
     }
 
-    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
-        self.style = style.into();
+    /// Sets where the items are placed along the main axis if they do not fill
+    /// the viewport, e.g. to vertically center a short menu within its block.
+    /// Defaults to [`MainAxisAlignment::Start`].
+    #[must_use]
+    pub fn main_axis_alignment(mut self, main_axis_alignment: MainAxisAlignment) -> Self {
+        self.main_axis_alignment = main_axis_alignment;
         self
     }
-}
-
-impl<'a, T: Copy + 'a> From<Vec<T>> for ListView<'a, T> {
-    fn from(value: Vec<T>) -> Self {
-        let item_count = value.len();
-        let builder = ListBuilder::new(move |context| (value[context.index], 1));
 
-        ListView::new(builder, item_count)
+    /// Ensures that the builder is invoked for `context_items` items before and
+    /// after the selected item on every render, even if they are not visible.
+    /// This lets context-sensitive builders, e.g. for diff hunk headers, rely
+    /// on the neighbors of the selection being built. The context items are
+    /// also never shrunk by [`TruncationPolicy::Shrink`].
+    #[must_use]
+    pub fn context_items(mut self, context_items: usize) -> Self {
+        self.context_items = context_items;
+        self
     }
-}
-
-/// This structure holds information about the item's position, selection
-/// status, scrolling behavior, and size along the cross axis.
-pub struct ListBuildContext {
-    /// The position of the item in the list.
-    pub index: usize,
-
-    /// A boolean flag indicating whether the item is currently selected.
-    pub is_selected: bool,
-
-    /// Defines the axis along which the list can be scrolled.
-    pub scroll_axis: ScrollAxis,
 
-    /// The size of the item along the cross axis.
-    pub cross_axis_size: u16,
-}
+    /// Renders placeholders for the items that are not loaded yet, e.g. for
+    /// lists backed by a data source that loads its items incrementally.
+    ///
+    /// Only the items that were marked as loaded with [`ListState::mark_loaded`]
+    /// are built. All other items take `main_axis_size` and are rendered with
+    /// the widget returned by `placeholder`, which receives the index of the
+    /// item and a tick that is incremented with every render, e.g. to animate
+    /// a shimmer.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{style::Stylize, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let list = ListView::new(builder, 1000).placeholder(1, |_, tick| {
+    ///     let shimmer = "░".repeat(usize::from(tick % 8));
+    ///     Line::from(shimmer).dark_gray()
+    /// });
+    ///
+    /// let mut state = ListState::default();
+    /// state.mark_loaded(0..100);
+    /// ```
+    #[must_use]
+    pub fn placeholder<F, W>(mut self, main_axis_size: u16, placeholder: F) -> Self
+    where
+        F: Fn(usize, u16) -> W + 'a,
+        W: Widget,
+    {
+        self.builder.placeholder_size = Some(main_axis_size);
+        self.placeholder = Some(Box::new(move |index, tick, area, buf| {
+            placeholder(index, tick).render(area, buf);
+        }));
+        self
+    }
 
-/// A type alias for the closure.
-type ListBuilderClosure<'a, T> = dyn Fn(&ListBuildContext) -> (T, u16) + 'a;
+    /// Attaches an [`ItemCache`] that is consulted before the builder is
+    /// evaluated during the layout, e.g. to share built items across frames.
+    /// Pass a mutable reference to keep ownership of the cache.
+    #[must_use]
+    pub fn item_cache<C: ItemCache<T> + 'a>(mut self, item_cache: C) -> Self {
+        self.item_cache = Some(Box::new(item_cache));
+        self
+    }
 
-/// The builder for constructing list elements in a `ListView<T>`
-pub struct ListBuilder<'a, T> {
-    closure: Box<ListBuilderClosure<'a, T>>,
-}
+    /// Sets the [`ScrollBehavior`] that decides which item is shown first on
+    /// the viewport, e.g. [`CenteredScrollBehavior`] to keep the selected item
+    /// centered. Pass a mutable reference to keep ownership of the behavior.
+    ///
+    /// Defaults to scrolling only as far as necessary to keep the selected item
+    /// and its scroll padding visible.
+    ///
+    /// [`CenteredScrollBehavior`]: crate::CenteredScrollBehavior
+    #[must_use]
+    pub fn scroll_behavior<S: ScrollBehavior + 'a>(mut self, scroll_behavior: S) -> Self {
+        self.scroll_behavior = Some(Box::new(scroll_behavior));
+        self
+    }
 
-impl<'a, T> ListBuilder<'a, T> {
-    /// Creates a new `ListBuilder` taking a closure as a parameter
+    /// Sets a render-time filter for selection changes, e.g. to prevent
+    /// leaving an item with unsaved edits or to skip disabled items. It
+    /// receives the selection of the last render and the new selection and
+    /// returns whether the change is allowed.
+    ///
+    /// The filter is applied when the list is rendered, not by the methods
+    /// of [`ListState`] that change the selection, so [`ListState::selected`]
+    /// may return a rejected item until the next render. If a change made by
+    /// [`ListState::next`] or [`ListState::previous`] is rejected, the
+    /// selection continues in the same direction until an item is accepted.
+    /// Otherwise, the selection of the last render is restored.
     ///
     /// # Example
     /// ```
     /// use ratatui::text::Line;
-    /// use tui_widget_list::ListBuilder;
-    ///
-    /// let builder = ListBuilder::new(|context| {
-    ///     let mut item = Line::from(format!("Item {:0}", context.index));
+    /// use tui_widget_list::{ListBuilder, ListView};
     ///
-    ///     // Return the size of the widget along the main axis.
-    ///     let main_axis_size = 1;
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
     ///
-    ///     (item, main_axis_size)
-    /// });
+    /// // Skip every other item
+    /// let list = ListView::new(builder, 10).selection_filter(|_, to| to.is_none_or(|i| i % 2 == 0));
     /// ```
-    pub fn new<F>(closure: F) -> Self
+    #[must_use]
+    pub fn selection_filter<F>(mut self, selection_filter: F) -> Self
     where
-        F: Fn(&ListBuildContext) -> (T, u16) + 'a,
+        F: Fn(Option<usize>, Option<usize>) -> bool + 'a,
     {
-        ListBuilder {
-            closure: Box::new(closure),
-        }
+        self.selection_filter = Some(Box::new(selection_filter));
+        self
     }
 
-    /// Method to call the stored closure.
-    pub(crate) fn call_closure(&self, context: &ListBuildContext) -> (T, u16) {
-        (self.closure)(context)
+    /// Patches `style` over the selected item after it was rendered, such that
+    /// the highlight spans the entire row, including the gutter and the
+    /// shortcut hint, instead of only the area of the item widget.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{style::{Color, Style}, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from("Item"), 1));
+    /// let list = ListView::new(builder, 10)
+    ///     .gutter(4, |index, _| Line::from(format!("{:>3}", index + 1)))
+    ///     .selection_band(Style::new().bg(Color::Blue));
+    /// ```
+    #[must_use]
+    pub fn selection_band<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_band = Some(style.into());
+        self
     }
-}
-
-/// Represents the scroll axis of a list.
-#[derive(Debug, Default, Clone, Copy)]
-pub enum ScrollAxis {
-    /// Indicates vertical scrolling. This is the default.
-    #[default]
-    Vertical,
 
-    /// Indicates horizontal scrolling.
-    Horizontal,
-}
+    /// Sets whether the list has the input focus, e.g. in applications with
+    /// several panes. Unfocused lists are rendered with the unfocused styles,
+    /// see [`ListView::unfocused_style`] and [`ListView::unfocused_selection_band`],
+    /// such that the builder does not have to style the items differently.
+    /// Defaults to true.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{style::{Color, Style}, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let has_focus = false;
+    /// let builder = ListBuilder::new(|context| (Line::from("Item"), 1));
+    /// let list = ListView::new(builder, 10)
+    ///     .selection_band(Style::new().bg(Color::Blue))
+    ///     .unfocused_style(Style::new().fg(Color::DarkGray))
+    ///     .focused(has_focus);
+    /// ```
+    #[must_use]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
 
-impl<T: Widget> StatefulWidget for ListView<'_, T> {
-    type State = ListState;
+    /// Patches `style` over the base style of the list while it is unfocused,
+    /// see [`ListView::focused`].
+    #[must_use]
+    pub fn unfocused_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.unfocused_style = Some(style.into());
+        self
+    }
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        state.set_num_elements(self.item_count);
-        state.set_infinite_scrolling(self.infinite_scrolling);
+    /// Sets the selection band while the list is unfocused, see
+    /// [`ListView::focused`]. Defaults to the [selection band] with the
+    /// [`Modifier::DIM`] modifier, such that the selection stays recognizable
+    /// but is less prominent than in the focused list.
+    ///
+    /// [selection band]: ListView::selection_band
+    /// [`Modifier::DIM`]: ratatui::style::Modifier::DIM
+    #[must_use]
+    pub fn unfocused_selection_band<S: Into<Style>>(mut self, style: S) -> Self {
+        self.unfocused_selection_band = Some(style.into());
+        self
+    }
 
-        // Set the base style
-        buf.set_style(area, self.style);
+    /// Tints the background of each item by a value, e.g. the CPU usage in a
+    /// process list. The closure returns the value of the item at the given
+    /// index, normalized to the range from `0.0` to `1.0`, which is mapped to
+    /// a background color by the `scale`. The tint is applied before the item
+    /// is rendered, so the styles of the item, e.g. of the selected item, take
+    /// precedence.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{style::Color, text::Line};
+    /// use tui_widget_list::{ColorScale, ListBuilder, ListView};
+    ///
+    /// let usage = [0.1, 0.9, 0.5];
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let scale = ColorScale::new(Color::Rgb(0, 60, 0), Color::Rgb(160, 0, 0));
+    /// let list = ListView::new(builder, usage.len()).heatmap(|index| usage[index], scale);
+    /// ```
+    #[must_use]
+    pub fn heatmap<F: Fn(usize) -> f32 + 'a>(mut self, value: F, scale: ColorScale) -> Self {
+        self.heatmap = Some((Box::new(value), scale));
+        self
+    }
 
-        // Set the base block
-        self.block.render(area, buf);
-        let area = self.block.inner_if_some(area);
+    /// Dims the list by patching `style` over its area, including the block,
+    /// e.g. while it is covered by a modal overlay. The selected item is not
+    /// dimmed, such that it remains recognizable.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{style::{Style, Stylize}, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let list = ListView::new(builder, 10).dimmed(Style::new().dim());
+    /// ```
+    #[must_use]
+    pub fn dimmed<S: Into<Style>>(mut self, style: S) -> Self {
+        self.dim_style = Some(style.into());
+        self
+    }
 
-        // List is empty
-        if self.item_count == 0 {
-            return;
-        }
+    /// Sets the style of the cells that a truncated item does not render itself.
+    ///
+    /// Truncated items are rendered at their full size into a hidden buffer that
+    /// is filled with this style, and only the visible part is copied into the
+    /// list. Cells that the item renders replace the underlying cells, including
+    /// their style, while changes outside of the item's area are discarded.
+    ///
+    /// Defaults to the style underneath the item, i.e. the base style of the
+    /// list patched with the style of its block, such that truncated items look
+    /// the same as items that are fully visible.
+    #[must_use]
+    pub fn base_style_under_truncation<S: Into<Style>>(mut self, style: S) -> Self {
+        self.base_style_under_truncation = Some(style.into());
+        self
+    }
 
-        // Set the dimension along the scroll axis and the cross axis
-        let (main_axis_size, cross_axis_size) = match self.scroll_axis {
-            ScrollAxis::Vertical => (area.height, area.width),
-            ScrollAxis::Horizontal => (area.width, area.height),
-        };
+    /// Lays out horizontal lists from right to left, e.g. for right-to-left
+    /// locales. The first item starts at the right edge, and scrolling and
+    /// truncation are mirrored accordingly. Vertical lists are not affected.
+    #[must_use]
+    pub fn right_to_left(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
 
-        // The coordinates of the first item with respect to the top left corner
-        let (mut scroll_axis_pos, cross_axis_pos) = match self.scroll_axis {
-            ScrollAxis::Vertical => (area.top(), area.left()),
-            ScrollAxis::Horizontal => (area.left(), area.top()),
-        };
+    /// Defines breakpoints that map the cross axis size to caller-defined size
+    /// classes, e.g. to switch between compact and full item layouts in narrow
+    /// terminals. Each breakpoint is a pair of the minimum cross axis size and
+    /// the size class. The size class of the largest breakpoint that fits is
+    /// passed to the builder in [`ListBuildContext::size_class`].
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// const COMPACT: usize = 0;
+    /// const FULL: usize = 1;
+    ///
+    /// let builder = ListBuilder::new(|context| {
+    ///     let item = match context.size_class {
+    ///         Some(FULL) => Line::from(format!("Item {}: Description", context.index)),
+    ///         _ => Line::from(format!("{}", context.index)),
+    ///     };
+    ///     (item, 1)
+    /// });
+    /// let list = ListView::new(builder, 10).size_classes(&[(0, COMPACT), (40, FULL)]);
+    /// ```
+    #[must_use]
+    pub fn size_classes(mut self, size_classes: &[(u16, usize)]) -> Self {
+        let mut size_classes = size_classes.to_vec();
+        size_classes.sort_by_key(|(min_size, _)| *min_size);
+        self.builder.size_classes = size_classes;
+        self
+    }
 
-        // Determine which widgets to show on the viewport and how much space they
-        // get assigned to.
-        let mut viewport = layout_on_viewport(
-            state,
-            &self.builder,
-            self.item_count,
+    /// Joins the borders of adjacent bordered items into shared lines, such
+    /// that the items look like the rows of a table instead of separate boxes.
+    ///
+    /// Each item overlaps the last row (or column for horizontal lists) of its
+    /// predecessor, i.e. it takes one cell less than the size returned by the
+    /// builder. The corners where the borders meet are replaced by junctions,
+    /// e.g. `├` and `┤`, for all border types of ratatui.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::widgets::Block;
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Block::bordered().title(format!("{}", context.index)), 3));
+    /// let list = ListView::new(builder, 10).joined_borders(true);
+    /// ```
+    #[must_use]
+    pub fn joined_borders(mut self, joined_borders: bool) -> Self {
+        self.builder.joined_borders = joined_borders;
+        self
+    }
+
+    /// Draws a separator between adjacent items, e.g. `│` between the tabs of
+    /// a horizontal list or `─` between the rows of a vertical list.
+    ///
+    /// The separator takes one cell along the main axis behind every item but
+    /// the last, which is added to the size returned by the builder. The
+    /// content of the span fills the separator across the cross axis. Its
+    /// style is patched over the list style, so unset colors and modifiers
+    /// are inherited from the list, while the selection style of an item does
+    /// not extend to its separator. Separators are not items, so neither
+    /// navigation nor the mouse selects them. The separator of the last
+    /// visible item may be cut off at the end of the viewport.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView, ScrollAxis};
+    ///
+    /// let area = Rect::new(0, 0, 8, 1);
+    /// let mut buf = Buffer::empty(area);
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("T{}", context.index)), 2));
+    /// let list = ListView::new(builder, 3)
+    ///     .scroll_axis(ScrollAxis::Horizontal)
+    ///     .separator("│");
+    /// list.render(area, &mut buf, &mut ListState::default());
+    ///
+    /// assert_eq!(buf, Buffer::with_lines(["T0│T1│T2"]));
+    /// ```
+    #[must_use]
+    pub fn separator<S: Into<Span<'a>>>(mut self, separator: S) -> Self {
+        self.separator = Some(separator.into());
+        self.builder.separated = true;
+        self
+    }
+
+    /// Sets how items that only partially fit onto the viewport are rendered.
+    /// Defaults to [`TruncationPolicy::Clip`].
+    #[must_use]
+    pub fn truncation_policy(mut self, truncation_policy: TruncationPolicy) -> Self {
+        self.builder.truncation_policy = truncation_policy;
+        self
+    }
+
+    /// Sets the easing curve of the expansion animation configured with
+    /// [`ListView::expansion_frames`]. Defaults to [`Easing::Linear`].
+    #[must_use]
+    pub fn expansion_easing(mut self, easing: Easing) -> Self {
+        self.expansion_easing = easing;
+        self
+    }
+
+    /// Omits the last item on the viewport if less than `min_partial_size` of it
+    /// would be visible, since a thin sliver of a large item is mostly visual
+    /// noise. The selected item is always rendered. Defaults to zero.
+    #[must_use]
+    pub fn min_partial_size(mut self, min_partial_size: u16) -> Self {
+        self.min_partial_size = min_partial_size;
+        self
+    }
+
+    /// Animates the inline expansion of [`ListView::expanded`] over the given
+    /// number of renders. If the selection changes, the detail widget of the
+    /// previously selected item collapses while the one of the newly selected
+    /// item expands. Zero disables the animation, which is the default.
+    #[must_use]
+    pub fn expansion_frames(mut self, frames: u16) -> Self {
+        self.expansion_frames = frames;
+        self
+    }
+
+    /// Adds a header widget of the given `height` that is rendered inside the block
+    /// above the items.
+    ///
+    /// The header is not an item, i.e. it is never scrolled and can not be selected.
+    /// This is useful for column headers or summaries that would otherwise need a
+    /// separate layout split in the app.
+    #[must_use]
+    pub fn header<W: Widget + 'a>(mut self, widget: W, height: u16) -> Self {
+        self.header = Some(Header {
+            height,
+            render: Box::new(move |area, buf| widget.render(area, buf)),
+        });
+        self
+    }
+
+    /// Sets the editing widget, e.g. a text area, that is rendered in place of
+    /// the edited item while [`ListState::start_editing`] is active. The editor
+    /// receives the visible area of the item.
+    #[must_use]
+    pub fn editor<W: Widget + 'a>(mut self, editor: W) -> Self {
+        self.editor = Some(Box::new(move |area, buf| editor.render(area, buf)));
+        self
+    }
+
+    /// Sets the widget that is rendered in place of the items if all visible
+    /// items have a size of zero along the main axis, which usually means that
+    /// the builder is misconfigured. By default, a message that describes the
+    /// problem is rendered. Use an empty widget, e.g. [`Line::default`], if
+    /// items without a size are intended.
+    ///
+    /// Without a diagnostic, debug builds additionally panic when this happens.
+    #[must_use]
+    pub fn zero_size_diagnostic<W: Widget + 'a>(mut self, diagnostic: W) -> Self {
+        self.zero_size_diagnostic = Some(Box::new(move |area, buf| diagnostic.render(area, buf)));
+        self
+    }
+
+    /// Adds a gutter of fixed `width` along the cross axis, e.g. for line numbers,
+    /// markers or icons.
+    ///
+    /// The closure receives the index of the item and whether it is selected, and
+    /// returns the content of the gutter next to that item. The gutter is managed by
+    /// the view, so the item widgets stay unaware of it, and the cross axis size
+    /// available to the items is reduced by `width`.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from("Item"), 1));
+    /// let list = ListView::new(builder, 10)
+    ///     .gutter(4, |index, _| Line::from(format!("{:>3}", index + 1)));
+    /// ```
+    #[must_use]
+    pub fn gutter<F>(mut self, width: u16, gutter: F) -> Self
+    where
+        F: Fn(usize, bool) -> Line<'a> + 'a,
+    {
+        self.gutter = Some(Gutter {
+            width,
+            closure: Box::new(gutter),
+        });
+        self
+    }
+
+    /// Limits the number of items that are built for the first time per render,
+    /// such that extremely expensive items do not block the input handling.
+    ///
+    /// Visible items that exceed the budget are rendered as placeholders, see
+    /// [`ListView::placeholder`], or left empty, and are built in one of the next
+    /// renders. Note that a placeholder also enables lazy loading, so the items
+    /// must be marked with [`ListState::mark_loaded`] in that case.
+    /// [`ListState::render_complete`] tells whether the application
+    /// should render another frame. Items that were built before do not count
+    /// against the budget, so combine the budget with an [`ListView::item_cache`]
+    /// to avoid rebuilding them on every render.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// let mut state = ListState::default();
+    /// let mut frames = 0;
+    /// loop {
+    ///     let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    ///     let list = ListView::new(builder, 10).build_budget(2);
+    ///     list.render(area, &mut Buffer::empty(area), &mut state);
+    ///     frames += 1;
+    ///     if state.render_complete() {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(frames, 3);
+    /// ```
+    #[must_use]
+    pub fn build_budget(mut self, max_builds: usize) -> Self {
+        self.build_budget = Some(max_builds);
+        self
+    }
+
+    /// Renders the shortcut keys of the items, see [`ListState::set_shortcut`],
+    /// at the start of the gutter. The gutter is widened by two cells for
+    /// vertical lists and by one row for horizontal lists, and it is added if
+    /// the list has no gutter yet.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let mut state = ListState::default();
+    /// state.set_number_shortcuts();
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from("Item"), 1));
+    /// let list = ListView::new(builder, 10).shortcut_hints(true);
+    /// ```
+    #[must_use]
+    pub fn shortcut_hints(mut self, shortcut_hints: bool) -> Self {
+        self.shortcut_hints = shortcut_hints;
+        self
+    }
+
+    /// Attaches an adapter that synchronizes the viewport position with an external
+    /// model. The position is published whenever it changes, and the adapter may
+    /// drive the selection of the list. See [`ScrollSync`] for details.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::{ListBuilder, ListView, ScrollPosition};
+    ///
+    /// let mut on_scroll = |position: ScrollPosition| {
+    ///     // Move a chart cursor to `position.offset`
+    /// };
+    /// let builder = ListBuilder::new(|context| (Line::from("Item"), 1));
+    /// let list = ListView::new(builder, 10).scroll_sync(&mut on_scroll);
+    /// ```
+    #[must_use]
+    pub fn scroll_sync<S: ScrollSync + 'a>(mut self, scroll_sync: S) -> Self {
+        self.scroll_sync = Some(Box::new(scroll_sync));
+        self
+    }
+
+    /// Registers an observer that receives the scroll progress in cells after
+    /// every layout, e.g. to keep a minimap, ruler or chart brush in sync with
+    /// the list.
+    ///
+    /// Only the items on the viewport are measured. The other items are
+    /// assumed to have the average size of the visible ones, so the progress
+    /// is exact if all items have the same size. Use [`ListView::item_offset`]
+    /// if the exact positions are needed.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView, ScrollProgress};
+    ///
+    /// let area = Rect::new(0, 0, 5, 4);
+    /// let mut state = ListState::default();
+    /// state.select(Some(9));
+    /// let mut progress = ScrollProgress::default();
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 2));
+    /// let list = ListView::new(builder, 10).on_scroll_progress(|p| progress = p);
+    /// list.render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert_eq!(
+    ///     progress,
+    ///     ScrollProgress { offset_cells: 16, total_cells: 20, viewport_cells: 4 }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn on_scroll_progress<F: FnMut(ScrollProgress) + 'a>(mut self, observer: F) -> Self {
+        self.scroll_progress = Some(Box::new(observer));
+        self
+    }
+
+    /// Returns the main axis start position of the item at `index`, in cells
+    /// measured from the start of the list.
+    ///
+    /// The item sizes are evaluated with the viewport dimensions of the last
+    /// render and cached in the [`ListState`], so repeated queries are cheap.
+    /// An `index` past the end of the list returns the total size of the list.
+    ///
+    /// The cache follows the selection and the sizes, marks and loaded items
+    /// that are managed by the state. If the data of the items changes, call
+    /// [`ListState::invalidate_item_offsets`].
+    ///
+    /// This is useful to render aligned gutters, timelines or position
+    /// markers next to the list.
+    pub fn item_offset(&mut self, state: &mut ListState, index: usize) -> usize {
+        // Measure the items like during rendering.
+        self.builder
+            .size_adjustments
+            .clone_from(&state.size_adjustments);
+        self.builder.marked.clone_from(&state.marked);
+        self.builder.item_count = self.item_count;
+        if self.placeholder.is_some() {
+            self.builder.loaded.clone_from(&state.loaded);
+        }
+
+        let main_axis_size = state.scroll_axis.main_size(state.inner_area);
+        let cross_axis_size = state.scroll_axis.cross_size(state.inner_area);
+        let key = SizeCacheKey {
+            selected: state.selected,
+            scroll_axis: state.scroll_axis,
             main_axis_size,
             cross_axis_size,
-            self.scroll_axis,
-            self.scroll_padding,
-        );
+        };
+        state
+            .size_cache
+            .offset(&self.builder, self.item_count, index, key)
+    }
 
-        let (start, end) = (
-            state.view_state.offset,
-            viewport.len() + state.view_state.offset,
+    /// Returns how many items, starting at `from_index`, fully fit into the
+    /// given `main_axis_size`, e.g. to determine how many rows fit on one page
+    /// for custom pagination or printing.
+    ///
+    /// The item sizes are evaluated with the builder, using the given
+    /// `cross_axis_size` and assuming that no item is selected.
+    #[must_use]
+    pub fn items_fitting_in(
+        &self,
+        main_axis_size: u16,
+        cross_axis_size: u16,
+        from_index: usize,
+    ) -> usize {
+        let mut available_size = main_axis_size;
+        let mut count = 0;
+        for index in from_index..self.item_count {
+            let context = ListBuildContext {
+                remaining_main_axis_size: Some(available_size),
+                viewport_index: Some(index - from_index),
+                ..self
+                    .builder
+                    .context(index, false, self.scroll_axis, cross_axis_size)
+            };
+            let item_main_axis_size = self.builder.main_axis_size(&context, main_axis_size);
+            let Some(remaining_size) = available_size.checked_sub(item_main_axis_size) else {
+                break;
+            };
+            available_size = remaining_size;
+            count += 1;
+        }
+        count
+    }
+
+    /// Specify whether the items should be stretched to fill the viewport.
+    ///
+    /// If enabled and all items fit onto the viewport, the remaining space
+    /// is distributed evenly among the items instead of leaving a blank tail.
+    /// This is useful for small fixed lists, like menus, that should fill
+    /// their block.
+    #[must_use]
+    pub fn fit_items(mut self, fit_items: bool) -> Self {
+        self.fit_items = fit_items;
+        self
+    }
+}
+
+impl<T> Styled for ListView<'_, T> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, T: Copy + 'a> From<Vec<T>> for ListView<'a, T> {
+    fn from(value: Vec<T>) -> Self {
+        let item_count = value.len();
+        let builder = ListBuilder::new(move |context| (value[context.index], 1));
+
+        ListView::new(builder, item_count)
+    }
+}
+
+/// This structure holds information about the item's position, selection
+/// status, scrolling behavior, and size along the cross axis.
+///
+/// Contexts can be constructed directly to unit test a builder closure, see
+/// [`ListBuildContext::new`] and [`ListBuilder::build_item`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ListBuildContext {
+    /// The position of the item in the list.
+    pub index: usize,
+
+    /// A boolean flag indicating whether the item is currently selected.
+    pub is_selected: bool,
+
+    /// Defines the axis along which the list can be scrolled.
+    pub scroll_axis: ScrollAxis,
+
+    /// The size of the item along the cross axis.
+    pub cross_axis_size: u16,
+
+    /// The space along the main axis that remains from the start of the item to
+    /// the end of the viewport. An item can use it to shrink or expand to exactly
+    /// fill the remaining area, e.g. a spacer or a last item that expands.
+    ///
+    /// This is only known for items that are built to be rendered and is `None`
+    /// while items are merely measured, e.g. for the scroll padding. Builders
+    /// that read it must be [`ListBuilder::positional`], otherwise a measured
+    /// item may be rendered without being built again.
+    pub remaining_main_axis_size: Option<u16>,
+
+    /// The position of the item within the viewport, where `0` is the first
+    /// visible item, e.g. to fade items near the edges of the viewport.
+    ///
+    /// Like the remaining main axis size, this is only known for items that
+    /// are built to be rendered by a [`ListBuilder::positional`] builder.
+    pub viewport_index: Option<usize>,
+
+    /// How items that do not fully fit onto the viewport are rendered.
+    pub truncation_policy: TruncationPolicy,
+
+    /// The caller-defined size class of the cross axis size, as configured
+    /// with [`ListView::size_classes`]. `None` if no size class applies.
+    pub size_class: Option<usize>,
+
+    /// A boolean flag indicating whether the item is marked, see
+    /// [`ListState::set_marked`].
+    pub is_marked: bool,
+}
+
+impl ListBuildContext {
+    /// Creates the context of the item at `index`, e.g. to test a builder
+    /// closure. The item is neither selected nor marked, the list scrolls
+    /// vertically and all sizes are unknown or zero. Use struct update syntax
+    /// to set other fields:
+    ///
+    /// ```
+    /// use tui_widget_list::ListBuildContext;
+    ///
+    /// let context = ListBuildContext {
+    ///     is_selected: true,
+    ///     cross_axis_size: 20,
+    ///     ..ListBuildContext::new(3)
+    /// };
+    /// ```
+    #[must_use]
+    pub fn new(index: usize) -> Self {
+        Self {
+            index,
+            ..Self::default()
+        }
+    }
+}
+
+/// Defines where the items are placed along the main axis if they do not
+/// fill the viewport.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MainAxisAlignment {
+    /// Places the items at the top or left of the viewport. This is the default.
+    #[default]
+    Start,
+
+    /// Centers the items within the viewport.
+    Center,
+
+    /// Places the items at the bottom or right of the viewport.
+    End,
+}
+
+/// Defines how items that only partially fit onto the viewport are rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TruncationPolicy {
+    /// Renders the item at its full size and clips the part that is outside of
+    /// the viewport. This is the default.
+    #[default]
+    Clip,
+
+    /// Does not render partially visible items, leaving their space blank. The
+    /// selected item is always rendered, and clipped if necessary.
+    Hide,
+
+    /// Renders the item into the reduced visible area instead of clipping it.
+    /// The item is built again with [`ListBuildContext::remaining_main_axis_size`]
+    /// set to the visible size, such that the widget can adapt to it.
+    Shrink,
+}
+
+/// A type alias for the closure.
+type ListBuilderClosure<'a, T> = dyn Fn(&ListBuildContext) -> (T, Constraint) + 'a;
+
+/// A type alias for the placeholder closure. It renders the placeholder of an
+/// item into the given area, receiving the index of the item and the render tick.
+type PlaceholderClosure<'a> = dyn Fn(usize, u16, Rect, &mut Buffer) + 'a;
+
+/// A type alias for the selection filter closure. It receives the previous
+/// and the new selection and returns whether the change is allowed.
+type SelectionFilterClosure<'a> = dyn Fn(Option<usize>, Option<usize>) -> bool + 'a;
+
+/// A type alias for the expansion closure. It returns the main axis size of the
+/// detail widget of an item, and renders it if an area and buffer are given.
+type ExpansionClosure<'a> = dyn Fn(usize, Option<(Rect, &mut Buffer)>) -> u16 + 'a;
+
+/// The builder for constructing list elements in a `ListView<T>`
+pub struct ListBuilder<'a, T> {
+    closure: Box<ListBuilderClosure<'a, T>>,
+
+    /// Constructs the detail widget that is inserted after the selected item.
+    pub(crate) expansion: Option<Box<ExpansionClosure<'a>>>,
+
+    /// The animation state of the expansion during rendering.
+    pub(crate) expansion_state: Option<ExpansionState>,
+
+    /// The minimum and maximum main axis size of interactively resized items.
+    pub(crate) size_bounds: (u16, u16),
+
+    /// The size adjustments of the resized items during rendering.
+    pub(crate) size_adjustments: HashMap<usize, i16>,
+
+    /// How partially visible items are rendered.
+    pub(crate) truncation_policy: TruncationPolicy,
+
+    /// The breakpoints of the size classes, sorted by the minimum cross axis size.
+    pub(crate) size_classes: Vec<(u16, usize)>,
+
+    /// The main axis size of placeholders for items that are not loaded yet.
+    /// If `None`, all items are considered loaded.
+    pub(crate) placeholder_size: Option<u16>,
+
+    /// The ranges of loaded items during rendering.
+    pub(crate) loaded: Vec<Range<usize>>,
+
+    /// The marked items during rendering.
+    pub(crate) marked: BTreeSet<usize>,
+
+    /// Whether adjacent items share their borders, see [`ListView::joined_borders`].
+    pub(crate) joined_borders: bool,
+
+    /// Whether the items are followed by a separator, see [`ListView::separator`].
+    pub(crate) separated: bool,
+
+    /// The number of items that may still be built for the first time during
+    /// rendering, see [`ListView::build_budget`]. If `None`, the budget is unlimited.
+    pub(crate) build_budget: Cell<Option<usize>>,
+
+    /// The items that were built in this or a previous render.
+    pub(crate) built: RefCell<BTreeSet<usize>>,
+
+    /// Whether an item was deferred to a later render during rendering.
+    pub(crate) deferred: Cell<bool>,
+
+    /// Whether panics of the closure are reported with the index, see [`ListBuilder::checked`].
+    pub(crate) checked: bool,
+
+    /// Whether the closure reads the position of the item on the viewport,
+    /// see [`ListBuilder::positional`].
+    pub(crate) positional: bool,
+
+    /// The item count of the list, used in the panic message of checked builders.
+    pub(crate) item_count: usize,
+}
+
+impl<'a, T> ListBuilder<'a, T> {
+    /// Creates a new `ListBuilder` taking a closure as a parameter
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::ListBuilder;
+    ///
+    /// let builder = ListBuilder::new(|context| {
+    ///     let mut item = Line::from(format!("Item {:0}", context.index));
+    ///
+    ///     // Return the size of the widget along the main axis.
+    ///     let main_axis_size = 1;
+    ///
+    ///     (item, main_axis_size)
+    /// });
+    /// ```
+    pub fn new<F>(closure: F) -> Self
+    where
+        F: Fn(&ListBuildContext) -> (T, u16) + 'a,
+    {
+        Self::with_constraints(move |context| {
+            let (widget, main_axis_size) = closure(context);
+            (widget, Constraint::Length(main_axis_size))
+        })
+    }
+
+    /// Creates a new `ListBuilder` taking a closure that returns the size of
+    /// the widget along the main axis as a [`Constraint`].
+    ///
+    /// The constraint is resolved against the main axis size of the viewport:
+    /// - [`Constraint::Length`] and [`Constraint::Min`] take the given size.
+    /// - [`Constraint::Max`] takes the given size, but at most the viewport size.
+    /// - [`Constraint::Percentage`] and [`Constraint::Ratio`] take a fraction of the viewport size.
+    /// - [`Constraint::Fill`] takes the full viewport size.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{layout::Constraint, text::Line};
+    /// use tui_widget_list::ListBuilder;
+    ///
+    /// let builder = ListBuilder::with_constraints(|context| {
+    ///     let item = Line::from(format!("Item {:0}", context.index));
+    ///
+    ///     // Each item takes a quarter of the viewport.
+    ///     (item, Constraint::Percentage(25))
+    /// });
+    /// ```
+    pub fn with_constraints<F>(closure: F) -> Self
+    where
+        F: Fn(&ListBuildContext) -> (T, Constraint) + 'a,
+    {
+        ListBuilder {
+            closure: Box::new(closure),
+            expansion: None,
+            expansion_state: None,
+            size_bounds: (1, u16::MAX),
+            size_adjustments: HashMap::new(),
+            truncation_policy: TruncationPolicy::default(),
+            size_classes: Vec::new(),
+            placeholder_size: None,
+            loaded: Vec::new(),
+            marked: BTreeSet::new(),
+            joined_borders: false,
+            separated: false,
+            build_budget: Cell::new(None),
+            built: RefCell::new(BTreeSet::new()),
+            deferred: Cell::new(false),
+            checked: false,
+            positional: false,
+            item_count: 0,
+        }
+    }
+
+    /// Creates a new `ListBuilder` over a slice of items. The closure receives a
+    /// reference to the item with the lifetime of the slice, so the returned
+    /// widget can borrow from the item instead of cloning it on every render.
+    ///
+    /// Items outside of the slice are never built, so the item count of the
+    /// [`ListView`] should be the length of the slice.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    /// # use ratatui::{buffer::Buffer, layout::Rect};
+    /// # let area = Rect::new(0, 0, 10, 2);
+    /// # let buf = &mut Buffer::empty(area);
+    ///
+    /// let names = vec![String::from("Alice"), String::from("Bob")];
+    ///
+    /// // The lines borrow the names, no string is cloned.
+    /// let builder = ListBuilder::from_slice(&names, |name, _| (Line::from(name.as_str()), 1));
+    /// let list = ListView::new(builder, names.len());
+    /// list.render(area, buf, &mut ListState::default());
+    /// ```
+    pub fn from_slice<D, F>(items: &'a [D], closure: F) -> Self
+    where
+        F: Fn(&'a D, &ListBuildContext) -> (T, u16) + 'a,
+    {
+        Self::new(move |context| closure(&items[context.index], context))
+    }
+
+    /// Reports panics of the closure with the index of the item and the item
+    /// count of the list in debug builds. This helps to diagnose the most
+    /// common mistake with builders: an item count that does not match the
+    /// length of the data that the closure indexes into, e.g. `SIZES[context.index]`.
+    ///
+    /// In release builds, the closure is called as is.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let names = ["Alice", "Bob"];
+    /// let builder = ListBuilder::new(|context| (Line::from(names[context.index]), 1)).checked();
+    ///
+    /// // Panics with "the builder panicked for the item at index 2 of a list with 3 items: ..."
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// let list = ListView::new(builder, 3);
+    /// list.render(area, &mut Buffer::empty(area), &mut ListState::default());
+    /// ```
+    #[must_use]
+    pub fn checked(mut self) -> Self {
+        self.checked = true;
+        self
+    }
+
+    /// Declares that the closure reads [`ListBuildContext::remaining_main_axis_size`]
+    /// or [`ListBuildContext::viewport_index`].
+    ///
+    /// Items are measured before their position on the viewport is known, e.g.
+    /// when the list scrolls up to the selected item. By default, the widget that
+    /// was built to measure an item is rendered, so the closure is called only once
+    /// per item. A positional builder is called again for these items, with the
+    /// position set.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::ListBuilder;
+    ///
+    /// let builder = ListBuilder::new(|context| {
+    ///     let top = context.viewport_index == Some(0);
+    ///     (Line::from(if top { "Top" } else { "Item" }), 1)
+    /// })
+    /// .positional();
+    /// ```
+    #[must_use]
+    pub fn positional(mut self) -> Self {
+        self.positional = true;
+        self
+    }
+
+    /// Sets the minimum and maximum main axis size to which an item can be
+    /// resized with [`ListState::resize_selected`]. Defaults to `1` and
+    /// `u16::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::ListBuilder;
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from("Pane"), 5)).size_bounds(3, 10);
+    /// ```
+    #[must_use]
+    pub fn size_bounds(mut self, min: u16, max: u16) -> Self {
+        self.size_bounds = (min, max.max(min));
+        self
+    }
+
+    /// Method to call the stored closure. The returned size constraint is
+    /// resolved against the main axis size of the viewport. The size of the
+    /// selected item includes the size of its expansion.
+    pub(crate) fn call_closure(
+        &self,
+        context: &ListBuildContext,
+        total_main_axis_size: u16,
+    ) -> (T, u16) {
+        self.call_closure_cached(context, total_main_axis_size, None)
+    }
+
+    /// Returns the main axis size of an item, without building placeholders.
+    pub(crate) fn main_axis_size(
+        &self,
+        context: &ListBuildContext,
+        total_main_axis_size: u16,
+    ) -> u16 {
+        match self.placeholder_size(context.index) {
+            Some(main_axis_size) => main_axis_size,
+            None => self.call_closure(context, total_main_axis_size).1,
+        }
+    }
+
+    /// Returns true if the item at `index` is marked.
+    pub(crate) fn is_marked(&self, index: usize) -> bool {
+        self.marked.contains(&index)
+    }
+
+    /// Returns the context for building the item at `index`. The context is
+    /// not bound to a position on the viewport, i.e. the remaining main axis
+    /// size and the viewport index are `None`.
+    pub(crate) fn context(
+        &self,
+        index: usize,
+        is_selected: bool,
+        scroll_axis: ScrollAxis,
+        cross_axis_size: u16,
+    ) -> ListBuildContext {
+        ListBuildContext {
+            index,
+            is_selected,
+            scroll_axis,
+            cross_axis_size,
+            remaining_main_axis_size: None,
+            viewport_index: None,
+            truncation_policy: self.truncation_policy,
+            size_class: self.size_class(cross_axis_size),
+            is_marked: self.is_marked(index),
+        }
+    }
+
+    /// Returns true if building the item at `index` exceeds the build budget,
+    /// such that the item is rendered as a placeholder until a later render.
+    /// Items that were built before do not count against the budget.
+    pub(crate) fn defer(&self, index: usize) -> bool {
+        let Some(budget) = self.build_budget.get() else {
+            return false;
+        };
+        let mut built = self.built.borrow_mut();
+        if built.contains(&index) {
+            return false;
+        }
+        if budget == 0 {
+            self.deferred.set(true);
+            return true;
+        }
+        self.build_budget.set(Some(budget - 1));
+        built.insert(index);
+        false
+    }
+
+    /// Calls the builder closure for the given context and returns the built
+    /// item and its size constraint, e.g. to unit test a builder without
+    /// rendering the list. Sizes returned by [`ListBuilder::new`] are wrapped
+    /// into a [`Constraint::Length`].
+    ///
+    /// Options of the builder, e.g. the size bounds, are not applied.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{layout::Constraint, text::Line};
+    /// use tui_widget_list::{ListBuildContext, ListBuilder};
+    ///
+    /// let builder = ListBuilder::new(|context| {
+    ///     let size = if context.is_selected { 2 } else { 1 };
+    ///     (Line::from(format!("Item {}", context.index)), size)
+    /// });
+    ///
+    /// let context = ListBuildContext { is_selected: true, ..ListBuildContext::new(3) };
+    /// let (item, constraint) = builder.build_item(&context);
+    /// assert_eq!(item, Line::from("Item 3"));
+    /// assert_eq!(constraint, Constraint::Length(2));
+    /// ```
+    pub fn build_item(&self, context: &ListBuildContext) -> (T, Constraint) {
+        self.build(context)
+    }
+
+    /// Returns the main axis size of the placeholder if the item at `index`
+    /// is not loaded yet, and `None` otherwise.
+    pub(crate) fn placeholder_size(&self, index: usize) -> Option<u16> {
+        self.placeholder_size
+            .filter(|_| !self.loaded.iter().any(|range| range.contains(&index)))
+    }
+
+    /// Calls the closure. Panics of checked builders are reported with the
+    /// index of the item and the item count in debug builds.
+    pub(crate) fn build(&self, context: &ListBuildContext) -> (T, Constraint) {
+        if !(cfg!(debug_assertions) && self.checked) {
+            return (self.closure)(context);
+        }
+        match panic::catch_unwind(AssertUnwindSafe(|| (self.closure)(context))) {
+            Ok(item) => item,
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("unknown panic");
+                panic!(
+                    "the builder panicked for the item at index {} of a list with {} items: {message}. \
+                     Does the item count of the list match the data of the builder?",
+                    context.index, self.item_count,
+                );
+            }
+        }
+    }
+
+    /// Like [`ListBuilder::call_closure`], but looks the item up in the item
+    /// cache first, if any.
+    pub(crate) fn call_closure_cached(
+        &self,
+        context: &ListBuildContext,
+        total_main_axis_size: u16,
+        item_cache: Option<&mut dyn ItemCache<T>>,
+    ) -> (T, u16) {
+        let (widget, constraint) = match item_cache {
+            Some(item_cache) => item_cache.get_or_build(context, &mut || self.build(context)),
+            None => self.build(context),
+        };
+        let mut main_axis_size = resolve_constraint(constraint, total_main_axis_size);
+        if let Some(&adjustment) = self.size_adjustments.get(&context.index) {
+            main_axis_size = self.resize(main_axis_size, adjustment);
+        }
+        let expansion_size = self.expansion_size(context.index, context.is_selected);
+        let mut main_axis_size = main_axis_size.saturating_add(expansion_size);
+        // The shared border overlaps the next item.
+        if self.joined_borders {
+            main_axis_size = main_axis_size.saturating_sub(1).max(1);
+        }
+        // The separator follows every item but the last.
+        if self.separated && context.index + 1 < self.item_count {
+            main_axis_size = main_axis_size.saturating_add(1);
+        }
+        (widget, main_axis_size)
+    }
+
+    /// Applies a size adjustment to the main axis size of an item, keeping
+    /// it within the size bounds.
+    fn resize(&self, main_axis_size: u16, adjustment: i16) -> u16 {
+        let (min, max) = self.size_bounds;
+        let size = i32::from(main_axis_size) + i32::from(adjustment);
+        units::clamp_u16(size.clamp(i32::from(min), i32::from(max)))
+    }
+
+    /// Limits the size adjustment of an item such that the resized item stays
+    /// within the size bounds. Otherwise, resizing beyond the bounds would have
+    /// to be undone before the item could be resized in the opposite direction.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn clamp_adjustment(
+        &self,
+        context: &ListBuildContext,
+        total_main_axis_size: u16,
+        adjustment: i16,
+    ) -> i16 {
+        let (_, constraint) = self.build(context);
+        let main_axis_size = resolve_constraint(constraint, total_main_axis_size);
+        let resized = self.resize(main_axis_size, adjustment);
+        (i32::from(resized) - i32::from(main_axis_size)) as i16
+    }
+
+    /// Returns the size class of the largest breakpoint that does not exceed
+    /// the cross axis size.
+    pub(crate) fn size_class(&self, cross_axis_size: u16) -> Option<usize> {
+        self.size_classes
+            .iter()
+            .rev()
+            .find(|(min_size, _)| *min_size <= cross_axis_size)
+            .map(|(_, size_class)| *size_class)
+    }
+
+    /// Returns the current main axis size of the expansion of an item. During
+    /// rendering, the size follows the expansion animation, otherwise only the
+    /// selected item is expanded.
+    pub(crate) fn expansion_size(&self, index: usize, is_selected: bool) -> u16 {
+        let Some(expansion) = &self.expansion else {
+            return 0;
+        };
+        match &self.expansion_state {
+            Some(state) if state.contains(index) => state.scale(index, expansion(index, None)),
+            Some(_) => 0,
+            None if is_selected => expansion(index, None),
+            None => 0,
+        }
+    }
+}
+
+/// Resolves a size constraint against the main axis size of the viewport.
+fn resolve_constraint(constraint: Constraint, total_main_axis_size: u16) -> u16 {
+    match constraint {
+        Constraint::Length(size) | Constraint::Min(size) => size,
+        Constraint::Max(size) => size.min(total_main_axis_size),
+        Constraint::Percentage(percentage) => {
+            units::scale(total_main_axis_size, u32::from(percentage), 100)
+        }
+        Constraint::Ratio(numerator, denominator) => {
+            units::scale(total_main_axis_size, numerator, denominator)
+        }
+        Constraint::Fill(_) => total_main_axis_size,
+    }
+}
+
+/// Represents the scroll axis of a list.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollAxis {
+    /// Indicates vertical scrolling. This is the default.
+    #[default]
+    Vertical,
+
+    /// Indicates horizontal scrolling.
+    Horizontal,
+}
+
+impl ScrollAxis {
+    /// Returns a [`Scrollbar`] that is oriented along the scroll axis, i.e. on
+    /// the right of vertical lists and at the bottom of horizontal lists, and
+    /// the remaining area for the list.
+    ///
+    /// The scrollbar is rendered into the full `area`, the list into the
+    /// returned area. The scrollbar state is provided by
+    /// [`ListState::scrollbar_state`].
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::StatefulWidget};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView, ScrollAxis};
+    ///
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut state = ListState::default();
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// let list = ListView::new(builder, 20).scroll_axis(ScrollAxis::Horizontal);
+    /// let (scrollbar, list_area) = list.scroll_axis.scrollbar(area);
+    ///
+    /// list.render(list_area, &mut buf, &mut state);
+    /// scrollbar.render(area, &mut buf, &mut state.scrollbar_state());
+    /// assert_eq!(list_area, Rect::new(0, 0, 10, 4));
+    /// ```
+    #[must_use]
+    pub fn scrollbar<'b>(self, area: Rect) -> (Scrollbar<'b>, Rect) {
+        match self {
+            Self::Vertical => (
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                Rect {
+                    width: area.width.saturating_sub(1),
+                    ..area
+                },
+            ),
+            Self::Horizontal => (
+                Scrollbar::new(ScrollbarOrientation::HorizontalBottom),
+                Rect {
+                    height: area.height.saturating_sub(1),
+                    ..area
+                },
+            ),
+        }
+    }
+
+    /// Returns the size of `rect` along the scroll axis, i.e. the height of
+    /// vertical lists and the width of horizontal lists.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use tui_widget_list::ScrollAxis;
+    ///
+    /// let rect = Rect::new(1, 2, 10, 5);
+    /// assert_eq!(ScrollAxis::Vertical.main_size(rect), 5);
+    /// assert_eq!(ScrollAxis::Horizontal.main_size(rect), 10);
+    /// ```
+    #[must_use]
+    pub const fn main_size(self, rect: Rect) -> u16 {
+        match self {
+            Self::Vertical => rect.height,
+            Self::Horizontal => rect.width,
+        }
+    }
+
+    /// Returns the size of `rect` along the cross axis, i.e. the width of
+    /// vertical lists and the height of horizontal lists.
+    #[must_use]
+    pub const fn cross_size(self, rect: Rect) -> u16 {
+        match self {
+            Self::Vertical => rect.width,
+            Self::Horizontal => rect.height,
+        }
+    }
+
+    /// Returns the start position of `rect` along the scroll axis, i.e. the
+    /// top of vertical lists and the left of horizontal lists.
+    #[must_use]
+    pub const fn main_pos(self, rect: Rect) -> u16 {
+        match self {
+            Self::Vertical => rect.y,
+            Self::Horizontal => rect.x,
+        }
+    }
+
+    /// Returns the start position of `rect` along the cross axis, i.e. the
+    /// left of vertical lists and the top of horizontal lists.
+    #[must_use]
+    pub const fn cross_pos(self, rect: Rect) -> u16 {
+        match self {
+            Self::Vertical => rect.x,
+            Self::Horizontal => rect.y,
+        }
+    }
+
+    /// Creates a [`Rect`] from positions and lengths along the scroll axis and
+    /// the cross axis. This is the inverse of the other axis helpers.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use tui_widget_list::ScrollAxis;
+    ///
+    /// let axis = ScrollAxis::Horizontal;
+    /// let rect = axis.to_rect(1, 2, 10, 5);
+    /// assert_eq!(rect, Rect::new(1, 2, 10, 5));
+    /// assert_eq!(axis.main_size(rect), 10);
+    /// assert_eq!(ScrollAxis::Vertical.to_rect(1, 2, 10, 5), Rect::new(2, 1, 5, 10));
+    /// ```
+    #[must_use]
+    pub const fn to_rect(
+        self,
+        main_pos: u16,
+        cross_pos: u16,
+        main_len: u16,
+        cross_len: u16,
+    ) -> Rect {
+        match self {
+            Self::Vertical => Rect::new(cross_pos, main_pos, cross_len, main_len),
+            Self::Horizontal => Rect::new(main_pos, cross_pos, main_len, cross_len),
+        }
+    }
+}
+
+impl<T: Widget> ListView<'_, T> {
+    /// Renders the list with the given selection into a separately owned
+    /// viewport. Unlike rendering with a [`ListState`], the selection is not
+    /// modified, such that the same selection can be rendered into several
+    /// differently sized panes, each with its own [`ViewState`].
+    ///
+    /// Features that keep state across renders, like the expansion animation or
+    /// interactive resizing, are not available.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListView, ViewState};
+    ///
+    /// let (mut small, mut large) = (ViewState::default(), ViewState::default());
+    /// for (area, view_state) in [(Rect::new(0, 0, 5, 2), &mut small), (Rect::new(0, 0, 5, 8), &mut large)] {
+    ///     let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    ///     let list = ListView::new(builder, 10);
+    ///     list.render_with_view_state(area, &mut Buffer::empty(area), Some(4), view_state);
+    /// }
+    /// assert_eq!((small.offset(), large.offset()), (3, 0));
+    /// ```
+    pub fn render_with_view_state(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        selected: Option<usize>,
+        view_state: &mut ViewState,
+    ) {
+        let mut state = ListState::default();
+        state.select(selected);
+        state.set_view_state(*view_state);
+        self.render(area, buf, &mut state);
+        *view_state = state.view_state();
+    }
+
+    /// Renders the list with a state that is shared behind a [`RefCell`], e.g.
+    /// an `Rc<RefCell<ListState>>` that is owned by a component framework.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state is currently borrowed.
+    ///
+    /// # Example
+    /// ```
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListState, ListView};
+    ///
+    /// let state = Rc::new(RefCell::new(ListState::default()));
+    /// let area = Rect::new(0, 0, 5, 3);
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 10).render_shared(area, &mut Buffer::empty(area), &state);
+    /// ```
+    pub fn render_shared(self, area: Rect, buf: &mut Buffer, state: &RefCell<ListState>) {
+        self.render(area, buf, &mut state.borrow_mut());
+    }
+}
+
+impl<'a, T: Widget> ListView<'a, T> {
+    /// Wraps the list into a [`Widget`] that renders with a default
+    /// [`ListState`], e.g. for read-only lists that are not interactive. This
+    /// allows to render the list with `Frame::render_widget` without carrying
+    /// a state around. No item is selected and the list shows the first items.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::Widget};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 2);
+    /// let mut buf = Buffer::empty(area);
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 10).stateless().render(area, &mut buf);
+    ///
+    /// assert_eq!(buf, Buffer::with_lines(["0    ", "1    "]));
+    /// ```
+    #[must_use]
+    pub fn stateless(self) -> StatelessListView<'a, T> {
+        self.stateless_scroll(0)
+    }
+
+    /// Like [`ListView::stateless`], but the list shows the items from the
+    /// item at `offset` onwards, e.g. for dashboards that display a window
+    /// into a long list. No item is selected, and the layout starts directly
+    /// at the offset instead of scrolling to a selection. Items that only
+    /// partially fit onto the viewport are truncated as usual.
+    ///
+    /// The offset is clamped to the last item.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::Widget};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let area = Rect::new(0, 0, 5, 2);
+    /// let mut buf = Buffer::empty(area);
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("{}", context.index)), 1));
+    /// ListView::new(builder, 10).stateless_scroll(4).render(area, &mut buf);
+    ///
+    /// assert_eq!(buf, Buffer::with_lines(["4    ", "5    "]));
+    /// ```
+    #[must_use]
+    pub fn stateless_scroll(self, offset: usize) -> StatelessListView<'a, T> {
+        StatelessListView { list: self, offset }
+    }
+}
+
+/// A [`ListView`] that renders with a default [`ListState`], see
+/// [`ListView::stateless`] and [`ListView::stateless_scroll`].
+///
+/// The list view itself does not implement [`Widget`], since calling `render`
+/// would be ambiguous for lists that are rendered with a state while both
+/// widget traits are in scope.
+pub struct StatelessListView<'a, T> {
+    list: ListView<'a, T>,
+    offset: usize,
+}
+
+impl<T: Widget> Widget for StatelessListView<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ListState::default();
+        if self.offset > 0 {
+            state.offset_anchored = true;
+            state.view_state.offset = self.offset.min(self.list.item_count.saturating_sub(1));
+        }
+        self.list.render(area, buf, &mut state);
+    }
+}
+
+impl<T: Widget> StatefulWidget for ListView<'_, T> {
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_list(area, buf, state);
+
+        // Count down the timed style overrides on every render, including the
+        // renders that end early, e.g. of a zoomed or an empty list.
+        state.tick_item_styles();
+    }
+}
+
+impl<T: Widget> ListView<'_, T> {
+    /// Renders the list. See [`StatefulWidget::render`].
+    fn render_list(mut self, area: Rect, buf: &mut Buffer, state: &mut ListState) {
+        state.set_num_elements(self.item_count);
+        state.set_infinite_scrolling(self.infinite_scrolling);
+
+        // Let the external model drive the selection
+        if let Some(scroll_sync) = &mut self.scroll_sync {
+            if let Some(index) = scroll_sync.poll().filter(|_| self.item_count > 0) {
+                state.select(Some(index.min(self.item_count - 1)));
+            }
+        }
+
+        // Let the filter veto a change of the selection
+        if let Some(selection_filter) = &self.selection_filter {
+            state.filter_selection(selection_filter);
+        }
+
+        // Pass the marked items to the layout
+        self.builder.marked.clone_from(&state.marked);
+        self.builder.item_count = self.item_count;
+
+        // Pass the build budget and the previously built items to the layout
+        state.render_complete = true;
+        if let Some(build_budget) = self.build_budget {
+            self.builder.build_budget.set(Some(build_budget));
+            self.builder.built.replace(std::mem::take(&mut state.built));
+        }
+
+        // Pass the loaded items to the layout
+        if self.placeholder.is_some() {
+            state.placeholder_tick = state.placeholder_tick.wrapping_add(1);
+            self.builder.loaded.clone_from(&state.loaded);
+        }
+
+        // Advance the expansion animation
+        if self.builder.expansion.is_some() {
+            state
+                .expansion
+                .advance(state.selected, self.expansion_frames, self.expansion_easing);
+            self.builder.expansion_state = Some(state.expansion);
+        }
+
+        let outer_area = area;
+
+        // Switch to the unfocused styles
+        if !self.focused {
+            if let Some(unfocused_style) = self.unfocused_style {
+                self.style = self.style.patch(unfocused_style);
+            }
+            self.selection_band = self.unfocused_selection_band.or(self
+                .selection_band
+                .map(|band| band.add_modifier(Modifier::DIM)));
+        }
+
+        // Set the base style
+        buf.set_style(area, self.style);
+
+        // Set the base block
+        if self.direction_aware_block {
+            let scrollbar_side = match self.scroll_axis {
+                ScrollAxis::Vertical => Borders::RIGHT,
+                ScrollAxis::Horizontal => Borders::BOTTOM,
+            };
+            self.block = self
+                .block
+                .map(|block| block.borders(Borders::ALL - scrollbar_side));
+        }
+        self.block.render(area, buf);
+        let area = self.block.inner_if_some(area);
+
+        // Render the header above the items
+        let area = match self.header {
+            Some(header) => {
+                let height = header.height.min(area.height);
+                (header.render)(Rect { height, ..area }, buf);
+                Rect {
+                    y: area.y + height,
+                    height: area.height - height,
+                    ..area
+                }
+            }
+            None => area,
+        };
+
+        // Render only the selected item over the entire area while zoomed
+        if let Some(index) = state.selected.filter(|_| state.zoomed.is_some()) {
+            if index < self.item_count {
+                state.set_inner_area(area, self.scroll_axis);
+                state.item_spans.clear();
+                let main_axis_size = self.scroll_axis.main_size(area);
+                let cross_axis_size = self.scroll_axis.cross_size(area);
+                let context = ListBuildContext {
+                    remaining_main_axis_size: Some(main_axis_size),
+                    viewport_index: Some(0),
+                    ..self
+                        .builder
+                        .context(index, true, self.scroll_axis, cross_axis_size)
+                };
+                let (widget, _) = self.builder.call_closure(&context, main_axis_size);
+                widget.render(area, buf);
+                state.item_spans.push(ItemSpan {
+                    index,
+                    offset: 0,
+                    size: main_axis_size,
+                    area,
+                    truncation: Truncation::None,
+                });
+                return;
+            }
+        }
+
+        // Split off the scrollbar at the end of the cross axis
+        let area = if self.scrollbar {
+            let (area, scrollbar_area) = split_scrollbar(area, self.scroll_axis);
+            let orientation = match self.scroll_axis {
+                ScrollAxis::Vertical => ScrollbarOrientation::VerticalRight,
+                ScrollAxis::Horizontal => ScrollbarOrientation::HorizontalBottom,
+            };
+            let [normal, hovered, dragged] = self.scrollbar_thumb_styles;
+            let thumb_style = match state.scrollbar_thumb_state() {
+                ScrollbarThumbState::Normal => normal,
+                ScrollbarThumbState::Hovered => hovered.or(normal),
+                ScrollbarThumbState::Dragged => dragged.or(normal),
+            };
+            state.scrollbar_mirrored =
+                self.right_to_left && self.scroll_axis == ScrollAxis::Horizontal;
+            Scrollbar::new(orientation)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .thumb_style(thumb_style.unwrap_or_default())
+                .render(scrollbar_area, buf, &mut state.built_in_scrollbar_state());
+            state.scrollbar_area = scrollbar_area;
+            area
+        } else {
+            state.scrollbar_area = Rect::default();
+            area
+        };
+
+        // Split off the gutter along the cross axis
+        let hint_width = match (self.shortcut_hints, self.scroll_axis) {
+            (false, _) => 0,
+            (true, ScrollAxis::Vertical) => 2,
+            (true, ScrollAxis::Horizontal) => 1,
+        };
+        let gutter_width = self.gutter.as_ref().map_or(0, |gutter| gutter.width) + hint_width;
+        let (gutter_area, area) = if gutter_width > 0 {
+            split_gutter(area, gutter_width, self.scroll_axis)
+        } else {
+            (Rect::default(), area)
+        };
+        state.set_inner_area(area, self.scroll_axis);
+        state.item_spans.clear();
+
+        // List is empty
+        if self.item_count == 0 {
+            if let Some(dim_style) = self.dim_style {
+                buf.set_style(outer_area, dim_style);
+            }
+            return;
+        }
+
+        // Set the dimension along the scroll axis and the cross axis
+        let main_axis_size = self.scroll_axis.main_size(area);
+        let cross_axis_size = self.scroll_axis.cross_size(area);
+
+        // Keep the size adjustment of the selected item within the size bounds
+        if let Some(index) = state.selected {
+            if let Some(adjustment) = state.size_adjustments.get_mut(&index) {
+                let context = self
+                    .builder
+                    .context(index, true, self.scroll_axis, cross_axis_size);
+                *adjustment = self
+                    .builder
+                    .clamp_adjustment(&context, main_axis_size, *adjustment);
+            }
+        }
+        self.builder.size_adjustments = state.size_adjustments.clone();
+
+        // The coordinates of the first item with respect to the top left corner
+        let mut scroll_axis_pos = self.scroll_axis.main_pos(area);
+        let cross_axis_pos = self.scroll_axis.cross_pos(area);
+
+        // Let the scroll behavior decide which item is shown first
+        if let Some(scroll_behavior) = &mut self.scroll_behavior {
+            let context = ScrollContext {
+                selected: state.selected,
+                offset: state.view_state.offset,
+                item_count: self.item_count,
+                viewport_size: main_axis_size,
+                scroll_padding: self.scroll_padding,
+            };
+            let builder = &self.builder;
+            let scroll_axis = self.scroll_axis;
+            let mut item_size = |index| {
+                let context = builder.context(
+                    index,
+                    state.selected == Some(index),
+                    scroll_axis,
+                    cross_axis_size,
+                );
+                builder.main_axis_size(&context, main_axis_size)
+            };
+            if let Some(first) = scroll_behavior.first_visible(&context, &mut item_size) {
+                state.view_state.offset = first.min(self.item_count - 1);
+                state.view_state.first_truncated = 0;
+            }
+        }
+
+        // Determine which widgets to show on the viewport and how much space they
+        // get assigned to.
+        let mut item_cache = self.item_cache.take();
+        let mut viewport = layout_on_viewport(
+            state,
+            &self.builder,
+            self.item_count,
+            main_axis_size,
+            cross_axis_size,
+            self.scroll_axis,
+            self.scroll_padding,
+            item_cache
+                .as_mut()
+                .map(|item_cache| &mut **item_cache as &mut dyn ItemCache<T>),
+        );
+        if self.build_budget.is_some() {
+            state.built = self.builder.built.take();
+            state.render_complete = !self.builder.deferred.get();
+        }
+        state.track_scroll_motion();
+
+        // Render a diagnostic instead of a blank viewport if no item has a size.
+        let is_zero_sized = main_axis_size > 0
+            && !viewport.is_empty()
+            && viewport.values().all(|element| element.main_axis_size == 0);
+        if is_zero_sized {
+            debug_assert!(
+                self.zero_size_diagnostic.is_some(),
+                "all {} items on the viewport of the list have a size of 0. Does the builder \
+                 return the size of the items along the scroll axis?",
+                viewport.len(),
+            );
+            match self.zero_size_diagnostic.take() {
+                Some(diagnostic) => diagnostic(area, buf),
+                None => Paragraph::new("All items of the list have a size of 0.")
+                    .wrap(Wrap { trim: true })
+                    .render(area, buf),
+            }
+            return;
+        }
+
+        let (start, end) = (
+            state.view_state.offset,
+            viewport.len() + state.view_state.offset,
+        );
+
+        // Build the context items around the selection that are not visible.
+        let context_range = state.selected.map_or(0..0, |selected| {
+            selected.saturating_sub(self.context_items)
+                ..(selected + self.context_items + 1).min(self.item_count)
+        });
+        for index in context_range.clone() {
+            if viewport.contains_key(&index) {
+                continue;
+            }
+            let context = self.builder.context(
+                index,
+                state.selected == Some(index),
+                self.scroll_axis,
+                cross_axis_size,
+            );
+            if self.builder.placeholder_size(index).is_none() {
+                let _ = self.builder.call_closure(&context, main_axis_size);
+            }
+        }
+
+        // Publish the viewport position to the external model
+        if let Some(mut scroll_sync) = self.scroll_sync.take() {
+            let position = ScrollPosition {
+                offset: state.view_state.offset,
+                selected: state.selected,
+                item_count: self.item_count,
+            };
+            if state.scroll_position != Some(position) {
+                state.scroll_position = Some(position);
+                scroll_sync.publish(position);
+            }
+        }
+
+        // Report the scroll progress to the observer
+        if let Some(mut scroll_progress) = self.scroll_progress.take() {
+            // Items off the viewport, including all items before the offset,
+            // are assumed to have the average size.
+            let offset = state.view_state.offset;
+            let size = |element: &ViewportElement<T>| usize::from(element.main_axis_size);
+            let known_cells: usize = viewport.values().map(size).sum();
+            let average_size = known_cells.checked_div(viewport.len()).unwrap_or(0);
+            let offset_cells =
+                offset * average_size + usize::from(state.view_state.first_truncated);
+            let total_cells = known_cells + (self.item_count - viewport.len()) * average_size;
+            scroll_progress(ScrollProgress {
+                offset_cells,
+                total_cells,
+                viewport_cells: main_axis_size,
+            });
+        }
+
+        // Distribute the unused space among the items if all of them fit.
+        let extra_sizes = if self.fit_items {
+            fit_items_extra_sizes(&viewport, self.item_count, main_axis_size)
+        } else {
+            Vec::new()
+        };
+
+        // Align the items along the main axis if they do not fill the viewport.
+        if extra_sizes.is_empty() {
+            scroll_axis_pos += main_axis_alignment_offset(
+                &viewport,
+                self.item_count,
+                main_axis_size,
+                self.main_axis_alignment,
+            );
+        }
+
+        let list_area = area;
+        let main_axis_start = self.scroll_axis.main_pos(list_area);
+        let mut tooltip_anchor = None;
+        let mut selected_area = None;
+        let mut selected_band = None;
+        for i in start..end {
+            let Some(mut element) = viewport.remove(&i) else {
+                break;
+            };
+            let extra_size = extra_sizes.get(i - start).copied().unwrap_or(0);
+            let visible_main_axis_size = element
+                .main_axis_size
+                .saturating_sub(element.truncation.value())
+                + extra_size;
+
+            // Omit the last item if only a sliver of it would be visible.
+            let is_sliver = i + 1 == end
+                && matches!(element.truncation, Truncation::Bot(_))
+                && visible_main_axis_size < self.min_partial_size
+                && state.selected != Some(i);
+            if is_sliver {
+                break;
+            }
+
+            let mut area = self.scroll_axis.to_rect(
+                scroll_axis_pos,
+                cross_axis_pos,
+                visible_main_axis_size,
+                cross_axis_size,
+            );
+
+            // Split off the separator behind the item. If it is cut off, the item
+            // itself is fully visible at the end of the viewport.
+            let mut truncation = element.truncation.clone();
+            let mut untruncated_size = element.main_axis_size;
+            let mut separator_area = None;
+            if self.separator.is_some() && i + 1 < self.item_count {
+                untruncated_size = untruncated_size.saturating_sub(1);
+                match truncation {
+                    Truncation::Bot(value) if value > 1 => truncation = Truncation::Bot(value - 1),
+                    Truncation::Bot(_) => truncation = Truncation::None,
+                    Truncation::Top(_) | Truncation::None => {
+                        let size = visible_main_axis_size.saturating_sub(1);
+                        area = self.scroll_axis.to_rect(
+                            scroll_axis_pos,
+                            cross_axis_pos,
+                            size,
+                            cross_axis_size,
+                        );
+                        separator_area = Some(self.scroll_axis.to_rect(
+                            scroll_axis_pos + size,
+                            cross_axis_pos,
+                            visible_main_axis_size - size,
+                            cross_axis_size,
+                        ));
+                    }
+                }
+            }
+
+            // Leave the space of hidden partial items blank.
+            let is_hidden = self.builder.truncation_policy == TruncationPolicy::Hide
+                && truncation.value() > 0
+                && state.selected != Some(i);
+            if is_hidden {
+                scroll_axis_pos += visible_main_axis_size;
+                continue;
+            }
+
+            // The span covers the item, but not its separator, which can't be
+            // hit. An item whose separator is cut off is not truncated.
+            let mut span_area = area;
+            if self.right_to_left && self.scroll_axis == ScrollAxis::Horizontal {
+                span_area.x = list_area.left() + list_area.right() - span_area.right();
+            }
+            state.item_spans.push(ItemSpan {
+                index: i,
+                offset: scroll_axis_pos - main_axis_start,
+                size: visible_main_axis_size,
+                area: span_area,
+                truncation: if truncation.value() > 0 {
+                    element.truncation.clone()
+                } else {
+                    Truncation::None
+                },
+            });
+
+            // Shrunk items are rendered directly into the visible area.
+            // Context items are never shrunk.
+            let is_shrunk = self.builder.truncation_policy == TruncationPolicy::Shrink
+                && truncation.value() > 0
+                && !context_range.contains(&i);
+            if is_shrunk {
+                truncation = Truncation::None;
+            }
+
+            // Extend items with joined borders by the border they share with the
+            // next item. If the extension does not fit, the border is truncated.
+            let is_joined = self.builder.joined_borders && element.widget.is_some();
+            if is_joined {
+                untruncated_size = untruncated_size.saturating_add(1);
+                let fits = match self.scroll_axis {
+                    ScrollAxis::Vertical => area.bottom() < list_area.bottom(),
+                    ScrollAxis::Horizontal => area.right() < list_area.right(),
+                };
+                match (fits, self.scroll_axis) {
+                    (true, ScrollAxis::Vertical) => area.height += 1,
+                    (true, ScrollAxis::Horizontal) => area.width += 1,
+                    (false, _) if !matches!(truncation, Truncation::Top(_)) => {
+                        truncation = Truncation::Bot(truncation.value() + 1);
+                    }
+                    (false, _) => {}
+                }
+            }
+
+            // Rebuild shrunk items with their visible size.
+            if is_shrunk && element.widget.is_some() {
+                let context = ListBuildContext {
+                    remaining_main_axis_size: Some(self.scroll_axis.main_size(area)),
+                    viewport_index: Some(i - start),
+                    ..self.builder.context(
+                        i,
+                        state.selected == Some(i),
+                        self.scroll_axis,
+                        cross_axis_size,
+                    )
+                };
+                element.widget = Some(self.builder.call_closure(&context, main_axis_size).0);
+            }
+
+            // Mirror horizontal lists that start from the right edge. The items
+            // themselves are not mirrored, so their truncated sides are swapped.
+            if self.right_to_left && self.scroll_axis == ScrollAxis::Horizontal {
+                area.x = list_area.left() + list_area.right() - area.right();
+                if let Some(separator_area) = &mut separator_area {
+                    separator_area.x =
+                        list_area.left() + list_area.right() - separator_area.right();
+                }
+                truncation = match truncation {
+                    Truncation::Top(value) => Truncation::Bot(value),
+                    Truncation::Bot(value) => Truncation::Top(value),
+                    Truncation::None => Truncation::None,
+                };
+            }
+
+            // Remember the border of the previous item that this item overlaps.
+            let shared_border = is_joined.then(|| {
+                let line = match self.scroll_axis {
+                    ScrollAxis::Vertical => Rect { height: 1, ..area },
+                    ScrollAxis::Horizontal if self.right_to_left => Rect {
+                        x: area.right().saturating_sub(1),
+                        width: 1,
+                        ..area
+                    },
+                    ScrollAxis::Horizontal => Rect { width: 1, ..area },
+                };
+                let symbols: Vec<String> = line
+                    .positions()
+                    .map(|position| buf.cell(position).map_or("", |c| c.symbol()).to_string())
+                    .collect();
+                (line, symbols)
+            });
+
+            // Render the gutter next to the item, starting with the shortcut hint.
+            let mut item_gutter_area = match self.scroll_axis {
+                ScrollAxis::Vertical => Rect {
+                    y: area.y,
+                    height: area.height,
+                    ..gutter_area
+                },
+                ScrollAxis::Horizontal => Rect {
+                    x: area.x,
+                    width: area.width,
+                    ..gutter_area
+                },
+            };
+            let row_gutter_area = item_gutter_area;
+            if hint_width > 0 {
+                let (hint_area, rest) =
+                    split_gutter(item_gutter_area, hint_width, self.scroll_axis);
+                if let Some(key) = state.shortcut_for(i) {
+                    Line::from(key.to_string()).render(hint_area, buf);
+                }
+                item_gutter_area = rest;
+            }
+            if let Some(gutter) = &self.gutter {
+                let line = (gutter.closure)(i, state.selected == Some(i));
+                line.render(item_gutter_area, buf);
+            }
+
+            // Tint the background of the item by its value. The item is rendered
+            // on top, such that its own styles, e.g. of the selection, take precedence.
+            if let Some((value, scale)) = &self.heatmap {
+                buf.set_style(area, Style::new().bg(scale.color_at(value(i))));
+            }
+
+            // The style of the cells of a truncated item that the item does not
+            // render itself. Defaults to the style underneath the item.
+            let truncation_style = self.base_style_under_truncation.unwrap_or_else(|| {
+                buf.cell(area.as_position())
+                    .map_or(self.style, ratatui::buffer::Cell::style)
+            });
+
+            // Render the item, followed by its expansion if it is expanded.
+            let item = match (element.widget, &self.placeholder) {
+                (Some(widget), _) => Slot::Item(widget),
+                (None, Some(placeholder)) => Slot::Placeholder {
+                    placeholder,
+                    index: i,
+                    tick: state.placeholder_tick,
+                },
+                (None, None) => Slot::Empty,
+            };
+            let detail_size = match item {
+                Slot::Item(_) => self.builder.expansion_size(i, state.selected == Some(i)),
+                Slot::Placeholder { .. } | Slot::Empty => 0,
+            };
+            let expansion = match &self.builder.expansion {
+                Some(expansion) if detail_size > 0 => Some(expansion),
+                _ => None,
+            };
+            if let Some(expansion) = expansion {
+                let expanded = Expanded {
+                    item,
+                    item_size: element.main_axis_size.saturating_sub(detail_size),
+                    index: i,
+                    expansion,
+                    scroll_axis: self.scroll_axis,
+                };
+                render_item(
+                    expanded,
+                    area,
+                    buf,
+                    untruncated_size,
+                    &truncation,
+                    truncation_style,
+                    self.scroll_axis,
+                );
+            } else {
+                render_item(
+                    item,
+                    area,
+                    buf,
+                    untruncated_size,
+                    &truncation,
+                    truncation_style,
+                    self.scroll_axis,
+                );
+            }
+
+            // Render the editor over the edited item.
+            if state.editing == Some(i) {
+                if let Some(editor) = self.editor.take() {
+                    Clear.render(area, buf);
+                    buf.set_style(area, truncation_style);
+                    editor(area, buf);
+                }
+            }
+
+            // Fill the separator behind the item, inheriting the list style.
+            if let (Some(separator), Some(separator_area)) = (&self.separator, separator_area) {
+                for position in separator_area.positions() {
+                    if let Some(cell) = buf.cell_mut(position) {
+                        cell.set_symbol(&separator.content)
+                            .set_style(separator.style);
+                    }
+                }
+            }
+
+            // Replace the corners where the borders meet by junctions.
+            if let Some((line, symbols)) = shared_border {
+                join_borders(buf, line, &symbols);
+            }
+
+            // Patch the style override over the item.
+            if let Some(item_style) = state.item_styles.get(&i) {
+                patch_item_style(buf, area, item_style);
+            }
+
+            if state.tooltip.as_ref().is_some_and(|(index, _)| *index == i) {
+                tooltip_anchor = Some(area);
+            }
+            if state.selected == Some(i) {
+                selected_area = Some(area);
+                selected_band = Some(if gutter_width > 0 {
+                    area.union(row_gutter_area)
+                } else {
+                    area
+                });
+            }
+
+            scroll_axis_pos += visible_main_axis_size;
+        }
+
+        // Highlight the selected item across the entire row.
+        if let (Some(style), Some(band)) = (self.selection_band, selected_band) {
+            buf.set_style(band, style);
+        }
+
+        // Dim everything but the selected item.
+        if let Some(dim_style) = self.dim_style {
+            dim_except(buf, outer_area, dim_style, selected_area);
+        }
+
+        // Render the tooltip above the list, anchored to its item.
+        if let (Some(anchor), Some((_, text))) = (tooltip_anchor, &state.tooltip) {
+            render_tooltip(text, anchor, buf);
+        }
+    }
+}
+
+/// Replaces the corners on a border `line` that an item shares with its
+/// predecessor by junctions. `symbols` are the symbols on the line before the
+/// item was rendered.
+fn join_borders(buf: &mut Buffer, line: Rect, symbols: &[String]) {
+    for (position, before) in line.positions().zip(symbols) {
+        let Some(cell) = buf.cell_mut(position) else {
+            continue;
+        };
+        if let Some(junction) = junction(before, cell.symbol()) {
+            cell.set_symbol(junction);
+        }
+    }
+}
+
+/// Returns the junction of a corner of a previous item and the corner of the
+/// next item that was rendered over it.
+fn junction(before: &str, after: &str) -> Option<&'static str> {
+    let junction = match (before, after) {
+        // Vertical lists
+        ("└" | "╰", "┌" | "╭") => "├",
+        ("┘" | "╯", "┐" | "╮") => "┤",
+        ("╚", "╔") => "╠",
+        ("╝", "╗") => "╣",
+        ("┗", "┏") => "┣",
+        ("┛", "┓") => "┫",
+        // Horizontal lists, from left to right and from right to left
+        ("┐" | "╮", "┌" | "╭") | ("┌" | "╭", "┐" | "╮") => "┬",
+        ("┘" | "╯", "└" | "╰") | ("└" | "╰", "┘" | "╯") => "┴",
+        ("╗", "╔") | ("╔", "╗") => "╦",
+        ("╝", "╚") | ("╚", "╝") => "╩",
+        ("┓", "┏") | ("┏", "┓") => "┳",
+        ("┛", "┗") | ("┗", "┛") => "┻",
+        _ => return None,
+    };
+    Some(junction)
+}
+
+/// Patches `style` over `area`, except for the cells in `exception`.
+fn dim_except(buf: &mut Buffer, area: Rect, style: Style, exception: Option<Rect>) {
+    for position in area.positions() {
+        if exception.is_some_and(|exception| exception.contains(position)) {
+            continue;
+        }
+        if let Some(cell) = buf.cell_mut(position) {
+            cell.set_style(style);
+        }
+    }
+}
+
+/// Renders a tooltip next to its anchor, see [`ListState::show_tooltip`]. The
+/// tooltip is clipped to the buffer.
+fn render_tooltip(text: &Text, anchor: Rect, buf: &mut Buffer) {
+    let size = Size::new(
+        units::saturating_u16(text.width()),
+        units::saturating_u16(text.height()),
+    );
+    let area = popup_area(anchor, *buf.area(), size);
+    Clear.render(area, buf);
+    text.render(area, buf);
+}
+
+/// Splits the area into the gutter area and the remaining area for the items.
+/// The gutter is placed at the start of the cross axis.
+fn split_gutter(area: Rect, width: u16, scroll_axis: ScrollAxis) -> (Rect, Rect) {
+    match scroll_axis {
+        ScrollAxis::Vertical => {
+            let width = width.min(area.width);
+            let gutter_area = Rect { width, ..area };
+            let area = Rect {
+                x: area.x + width,
+                width: area.width - width,
+                ..area
+            };
+            (gutter_area, area)
+        }
+        ScrollAxis::Horizontal => {
+            let height = width.min(area.height);
+            let gutter_area = Rect { height, ..area };
+            let area = Rect {
+                y: area.y + height,
+                height: area.height - height,
+                ..area
+            };
+            (gutter_area, area)
+        }
+    }
+}
+
+/// Splits `area` into the area of the items and the track of the scrollbar,
+/// which takes the last column of vertical lists and the last row of
+/// horizontal lists.
+fn split_scrollbar(area: Rect, scroll_axis: ScrollAxis) -> (Rect, Rect) {
+    match scroll_axis {
+        ScrollAxis::Vertical => {
+            let width = area.width.saturating_sub(1);
+            let scrollbar_area = Rect {
+                x: area.x + width,
+                width: area.width - width,
+                ..area
+            };
+            (Rect { width, ..area }, scrollbar_area)
+        }
+        ScrollAxis::Horizontal => {
+            let height = area.height.saturating_sub(1);
+            let scrollbar_area = Rect {
+                y: area.y + height,
+                height: area.height - height,
+                ..area
+            };
+            (Rect { height, ..area }, scrollbar_area)
+        }
+    }
+}
+
+/// Calculates the offset of the first item for the given alignment if all
+/// items fit onto the viewport and leave some space unused.
+fn main_axis_alignment_offset<T>(
+    viewport: &HashMap<usize, ViewportElement<T>>,
+    item_count: usize,
+    total_main_axis_size: u16,
+    alignment: MainAxisAlignment,
+) -> u16 {
+    if viewport.len() != item_count || viewport.values().any(|e| e.truncation.value() > 0) {
+        return 0;
+    }
+
+    let used_size: u16 = viewport.values().map(|e| e.main_axis_size).sum();
+    let remaining_size = total_main_axis_size.saturating_sub(used_size);
+    match alignment {
+        MainAxisAlignment::Start => 0,
+        MainAxisAlignment::Center => remaining_size / 2,
+        MainAxisAlignment::End => remaining_size,
+    }
+}
+
+/// Calculates how much additional space each item receives if all items fit
+/// onto the viewport. The remainder is assigned to the first items.
+///
+/// Returns an empty vector if the items do not fit onto the viewport.
+fn fit_items_extra_sizes<T>(
+    viewport: &HashMap<usize, ViewportElement<T>>,
+    item_count: usize,
+    total_main_axis_size: u16,
+) -> Vec<u16> {
+    if viewport.len() != item_count || viewport.values().any(|e| e.truncation.value() > 0) {
+        return Vec::new();
+    }
+
+    let used_size: u16 = viewport.values().map(|e| e.main_axis_size).sum();
+    let Some(remaining_size) = total_main_axis_size.checked_sub(used_size) else {
+        return Vec::new();
+    };
+
+    let count = units::saturating_u16(item_count);
+    let (share, remainder) = (remaining_size / count, remaining_size % count);
+    (0..count)
+        .map(|i| if i < remainder { share + 1 } else { share })
+        .collect()
+}
+
+/// Patches a style override over an item. Fading styles are blended with the
+/// underlying cells according to the remaining renders.
+fn patch_item_style(buf: &mut Buffer, area: Rect, item_style: &ItemStyle) {
+    let (Some(fade_renders), Some(remaining_renders)) =
+        (item_style.fade_renders, item_style.remaining_renders)
+    else {
+        buf.set_style(area, item_style.style);
+        return;
+    };
+    let (weight, total) = (remaining_renders.min(fade_renders), fade_renders.max(1));
+
+    // Non-blendable colors and modifiers are applied in the first half of the fade.
+    let first_half = u32::from(weight) * 2 >= u32::from(total);
+    if first_half {
+        buf.set_style(
+            area,
+            Style {
+                fg: None,
+                bg: None,
+                ..item_style.style
+            },
+        );
+    }
+
+    for position in area.positions() {
+        let Some(cell) = buf.cell_mut(position) else {
+            continue;
+        };
+        if let Some(fg) = item_style.style.fg {
+            match blend_color(cell.fg, fg, weight, total) {
+                Some(color) => cell.fg = color,
+                None if first_half => cell.fg = fg,
+                None => {}
+            }
+        }
+        if let Some(bg) = item_style.style.bg {
+            match blend_color(cell.bg, bg, weight, total) {
+                Some(color) => cell.bg = color,
+                None if first_half => cell.bg = bg,
+                None => {}
+            }
+        }
+    }
+}
+
+/// Blends two rgb colors, where `weight / total` is the share of `to`.
+/// Returns `None` if one of the colors is not an rgb color.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn blend_color(from: Color, to: Color, weight: u16, total: u16) -> Option<Color> {
+    let (Color::Rgb(r0, g0, b0), Color::Rgb(r1, g1, b1)) = (from, to) else {
+        return None;
+    };
+    let (weight, total) = (u32::from(weight), u32::from(total));
+    let blend = |c0: u8, c1: u8| -> u8 {
+        ((u32::from(c0) * (total - weight) + u32::from(c1) * weight) / total) as u8
+    };
+    Some(Color::Rgb(blend(r0, r1), blend(g0, g1), blend(b0, b1)))
+}
+
+/// An item or the placeholder of an item that is not loaded yet.
+enum Slot<'a, 'b, T> {
+    Item(T),
+    Placeholder {
+        placeholder: &'b PlaceholderClosure<'a>,
+        index: usize,
+        tick: u16,
+    },
+    Empty,
+}
+
+impl<T: Widget> Widget for Slot<'_, '_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self {
+            Self::Item(widget) => widget.render(area, buf),
+            Self::Placeholder {
+                placeholder,
+                index,
+                tick,
+            } => placeholder(index, tick, area, buf),
+            Self::Empty => {}
+        }
+    }
+}
+
+/// An item followed by its detail widget along the main axis.
+struct Expanded<'a, 'b, T> {
+    item: T,
+    item_size: u16,
+    index: usize,
+    expansion: &'b ExpansionClosure<'a>,
+    scroll_axis: ScrollAxis,
+}
+
+impl<T: Widget> Widget for Expanded<'_, '_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (item_area, detail_area) = match self.scroll_axis {
+            ScrollAxis::Vertical => {
+                let item_size = self.item_size.min(area.height);
+                let item_area = Rect {
+                    height: item_size,
+                    ..area
+                };
+                let detail_area = Rect {
+                    y: area.y + item_size,
+                    height: area.height - item_size,
+                    ..area
+                };
+                (item_area, detail_area)
+            }
+            ScrollAxis::Horizontal => {
+                let item_size = self.item_size.min(area.width);
+                let item_area = Rect {
+                    width: item_size,
+                    ..area
+                };
+                let detail_area = Rect {
+                    x: area.x + item_size,
+                    width: area.width - item_size,
+                    ..area
+                };
+                (item_area, detail_area)
+            }
+        };
+        self.item.render(item_area, buf);
+        (self.expansion)(self.index, Some((detail_area, buf)));
+    }
+}
+
+/// Render a widget into a buffer, taking into account its truncation.
+pub(crate) fn render_item<T: Widget>(
+    item: T,
+    available_area: Rect,
+    buf: &mut Buffer,
+    untruncated_size: u16,
+    truncation: &Truncation,
+    base_style: Style,
+    scroll_axis: ScrollAxis,
+) {
+    if truncation.value() > 0 {
+        render_truncated(
+            item,
+            available_area,
+            buf,
+            untruncated_size,
+            truncation,
+            base_style,
+            scroll_axis,
+        );
+    } else {
+        item.render(available_area, buf);
+    }
+}
+
+/// Render a truncated widget into a buffer. The method renders the widget fully into
+/// a hidden buffer and moves the visible content into `buf`.
+fn render_truncated<T: Widget>(
+    item: T,
+    available_area: Rect,
+    buf: &mut Buffer,
+    untruncated_size: u16,
+    truncation: &Truncation,
+    base_style: Style,
+    scroll_axis: ScrollAxis,
+) {
+    // Create an hidden buffer for rendering the truncated element
+    let mut hidden_buffer = Buffer::empty(scroll_axis.to_rect(
+        scroll_axis.main_pos(available_area),
+        scroll_axis.cross_pos(available_area),
+        untruncated_size,
+        scroll_axis.cross_size(available_area),
+    ));
+    hidden_buffer.set_style(hidden_buffer.area, base_style);
+    item.render(hidden_buffer.area, &mut hidden_buffer);
+
+    // Copy the visible part from the hidden buffer to the main buffer
+    match scroll_axis {
+        ScrollAxis::Vertical => {
+            let offset = match truncation {
+                Truncation::Top(value) => *value,
+                _ => 0,
+            };
+            for y in available_area.top()..available_area.bottom() {
+                let y_off = y + offset;
+                for x in available_area.left()..available_area.right() {
+                    if let Some(to) = buf.cell_mut(Position::new(x, y)) {
+                        if let Some(from) = hidden_buffer.cell(Position::new(x, y_off)) {
+                            *to = from.clone();
+                        }
+                    }
+                }
+            }
+        }
+        ScrollAxis::Horizontal => {
+            let offset = match truncation {
+                Truncation::Top(value) => *value,
+                _ => 0,
+            };
+            for x in available_area.left()..available_area.right() {
+                let x_off = x + offset;
+                for y in available_area.top()..available_area.bottom() {
+                    if let Some(to) = buf.cell_mut(Position::new(x, y)) {
+                        if let Some(from) = hidden_buffer.cell(Position::new(x_off, y)) {
+                            *to = from.clone();
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ListBuilder;
+    use ratatui::widgets::Block;
+
+    use super::*;
+    use ratatui::{style::Modifier, widgets::Borders};
+
+    struct TestItem {}
+    impl Widget for TestItem {
+        fn render(self, area: Rect, buf: &mut Buffer)
+        where
+            Self: Sized,
+        {
+            Block::default().borders(Borders::ALL).render(area, buf);
+        }
+    }
+
+    fn test_data(total_height: u16) -> (Rect, Buffer, ListView<'static, TestItem>, ListState) {
+        let area = Rect::new(0, 0, 5, total_height);
+        let list = ListView::new(ListBuilder::new(|_| (TestItem {}, 3)), 3);
+        (area, Buffer::empty(area), list, ListState::default())
+    }
+
+    #[test]
+    fn not_truncated() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(9);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn empty_list() {
+        // given
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|_| (TestItem {}, 0));
+        let list = ListView::new(builder, 0);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["     ", "     "]))
+    }
+
+    #[test]
+    fn zero_size() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(0);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::empty(area))
+    }
+
+    #[test]
+    fn truncated_bot() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(8);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+            ]),
+        )
+    }
+
+    #[test]
+    fn truncated_top() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(8);
+        state.select(Some(2));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn scroll_up() {
+        let (area, mut buf, list, mut state) = test_data(8);
+        // Select last element and render
+        state.select(Some(2));
+        list.render(area, &mut buf, &mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        );
+
+        // Select first element and render
+        let (_, mut buf, list, _) = test_data(8);
+        state.select(Some(1));
+        list.render(area, &mut buf, &mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn fit_items() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(11);
+        let list = list.fit_items(true);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn constraint_sizes() {
+        // given
+        let area = Rect::new(0, 0, 5, 9);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::with_constraints(|context| {
+            let constraint = match context.index {
+                0 => Constraint::Length(3),
+                1 => Constraint::Ratio(1, 3),
+                _ => Constraint::Percentage(40),
+            };
+            (TestItem {}, constraint)
+        });
+        let list = ListView::new(builder, 3);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn item_offset() {
+        // given
+        let area = Rect::new(0, 0, 5, 10);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| (TestItem {}, context.index as u16 + 1));
+        let list = ListView::new(builder, 3);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+        let builder = ListBuilder::new(|context| (TestItem {}, context.index as u16 + 1));
+        let mut list = ListView::new(builder, 3);
+
+        // then
+        assert_eq!(list.item_offset(&mut state, 0), 0);
+        assert_eq!(list.item_offset(&mut state, 1), 1);
+        assert_eq!(list.item_offset(&mut state, 2), 3);
+        assert_eq!(list.item_offset(&mut state, 3), 6);
+        assert_eq!(list.item_offset(&mut state, 10), 6);
+    }
+
+    #[test]
+    fn item_offset_follows_state() {
+        // given
+        let area = Rect::new(0, 0, 5, 10);
+        let mut state = ListState::default();
+        let list = || {
+            let builder =
+                ListBuilder::new(|context| (TestItem {}, if context.is_marked { 2 } else { 1 }));
+            ListView::new(builder, 10)
+        };
+        state.select(Some(0));
+        list().render(area, &mut Buffer::empty(area), &mut state);
+        assert_eq!(list().item_offset(&mut state, 5), 5);
+
+        // when
+        state.resize_selected(3);
+        list().render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        assert_eq!(list().item_offset(&mut state, 5), 8);
+
+        // when
+        state.set_marked(1, true);
+
+        // then
+        assert_eq!(list().item_offset(&mut state, 5), 9);
+    }
+
+    #[test]
+    fn item_offset_is_incremental() {
+        // given
+        let area = Rect::new(0, 0, 5, 3);
+        let mut state = ListState::default();
+        let builds = RefCell::new(0);
+        let list = |item_count: usize| {
+            let builder = ListBuilder::new(|context| {
+                *builds.borrow_mut() += 1;
+                (TestItem {}, if context.is_selected { 2 } else { 1 })
+            });
+            ListView::new(builder, item_count)
+        };
+        list(100).render(area, &mut Buffer::empty(area), &mut state);
+        assert_eq!(list(100).item_offset(&mut state, 100), 100);
+
+        // when
+        builds.replace(0);
+        state.select(Some(99));
+        let offset = list(101).item_offset(&mut state, 101);
+
+        // then
+        assert_eq!(offset, 102);
+        assert_eq!(*builds.borrow(), 2);
+    }
+
+    #[test]
+    fn scroll_progress_measures_visible_items() {
+        // given
+        let area = Rect::new(0, 0, 5, 4);
+        let mut state = ListState::default();
+        let builds = RefCell::new(0);
+        let mut progress = ScrollProgress::default();
+        let builder = ListBuilder::new(|_| {
+            *builds.borrow_mut() += 1;
+            (TestItem {}, 2)
+        });
+        state.select(Some(500));
+
+        // when
+        ListView::new(builder, 1000)
+            .on_scroll_progress(|p| progress = p)
+            .render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        let expected = ScrollProgress {
+            offset_cells: 998,
+            total_cells: 2000,
+            viewport_cells: 4,
+        };
+        assert_eq!(progress, expected);
+        assert!(*builds.borrow() < 10);
+    }
+
+    #[test]
+    fn item_offset_tracks_unselected_items() {
+        // given
+        let area = Rect::new(0, 0, 5, 3);
+        let mut state = ListState::default();
+        let sizes = RefCell::new(vec![1; 10]);
+        let list = || {
+            let builder = ListBuilder::new(|context| (TestItem {}, sizes.borrow()[context.index]));
+            ListView::new(builder, 10)
+        };
+        state.select(Some(2));
+        list().render(area, &mut Buffer::empty(area), &mut state);
+        assert_eq!(list().item_offset(&mut state, 10), 10);
+
+        // when
+        state.resize_selected(2);
+        state.select(Some(5));
+        list().render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        assert_eq!(list().item_offset(&mut state, 10), 12);
+
+        // when
+        sizes.borrow_mut()[7] = 3;
+        state.invalidate_item_offsets();
+        list().render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        assert_eq!(list().item_offset(&mut state, 10), 14);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the builder panicked for the item at index 2 of a list with 3 items"
+    )]
+    fn checked_builder() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let sizes = [1, 1];
+        let builder = ListBuilder::new(|c| (TestItem {}, sizes[c.index])).checked();
+
+        // when
+        ListView::new(builder, 3).render(area, &mut Buffer::empty(area), &mut ListState::default());
+    }
+
+    #[test]
+    fn selection_band_spans_gutter() {
+        // given
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+        let list = ListView::new(builder, 2)
+            .gutter(2, |i, _| Line::from(format!("{i}")))
+            .selection_band(Style::new().bg(Color::Blue));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(["0 0 ", "1 1 "]);
+        expected.set_style(Rect::new(0, 1, 4, 1), Style::new().bg(Color::Blue));
+        assert_buffer_eq(buf, expected);
+    }
+
+    #[test]
+    fn zero_size_diagnostic() {
+        // given
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buf = Buffer::empty(area);
+        let builder = ListBuilder::new(|_| (Line::from("Item"), 0));
+        let list = ListView::new(builder, 3).zero_size_diagnostic(Line::from("Oops"));
+
+        // when
+        list.render(area, &mut buf, &mut ListState::default());
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["Oops ", "     "]));
+    }
+
+    #[test]
+    #[should_panic(expected = "all 3 items on the viewport of the list have a size of 0")]
+    fn zero_size_items_panic_in_debug_builds() {
+        // given
+        let area = Rect::new(0, 0, 5, 2);
+        let builder = ListBuilder::new(|_| (Line::from("Item"), 0));
+        let list = ListView::new(builder, 3);
+
+        // when
+        list.render(area, &mut Buffer::empty(area), &mut ListState::default());
+    }
+
+    #[test]
+    fn stateless_scroll_truncates_last_item() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut buf = Buffer::empty(area);
+        let builder = ListBuilder::new(|c| {
+            let text = Text::from(vec![Line::from(format!("{}a", c.index)), Line::from("b")]);
+            (text, 2)
+        });
+
+        // when
+        ListView::new(builder, 4)
+            .stateless_scroll(7)
+            .render(area, &mut buf);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["3a ", "b  ", "   "]));
+
+        // given
+        let mut buf = Buffer::empty(area);
+        let builder = ListBuilder::new(|c| {
+            let text = Text::from(vec![Line::from(format!("{}a", c.index)), Line::from("b")]);
+            (text, 2)
+        });
+
+        // when
+        ListView::new(builder, 4)
+            .stateless_scroll(1)
+            .render(area, &mut buf);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["1a ", "b  ", "2a "]));
+    }
+
+    #[test]
+    fn unfocused_styles() {
+        // given
+        let area = Rect::new(0, 0, 2, 2);
+        let mut state = ListState::default();
+        state.select(Some(0));
+        let render = |focused: bool, state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 2)
+                .selection_band(Style::new().bg(Color::Blue))
+                .unfocused_style(Style::new().fg(Color::Gray))
+                .focused(focused)
+                .render(area, &mut buf, state);
+            buf
+        };
+
+        // when
+        let focused = render(true, &mut state);
+        let unfocused = render(false, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(["0 ", "1 "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().bg(Color::Blue));
+        assert_buffer_eq(focused, expected);
+
+        let mut expected = Buffer::with_lines(["0 ", "1 "]);
+        expected.set_style(area, Style::new().fg(Color::Gray));
+        expected.set_style(
+            Rect::new(0, 0, 2, 1),
+            Style::new().bg(Color::Blue).add_modifier(Modifier::DIM),
+        );
+        assert_buffer_eq(unfocused, expected);
+    }
+
+    #[test]
+    fn zoom_selected() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ListState::default();
+        state.select(Some(4));
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 10).render(area, &mut buf, state);
+            buf
+        };
+        render(&mut state);
+
+        // when
+        state.zoom_selected(true);
+        let zoomed = render(&mut state);
+        state.zoom_selected(false);
+        let unzoomed = render(&mut state);
+
+        // then
+        assert_buffer_eq(zoomed, Buffer::with_lines(["4  ", "   ", "   "]));
+        assert_buffer_eq(unzoomed, Buffer::with_lines(["2  ", "3  ", "4  "]));
+    }
+
+    #[test]
+    fn direction_aware_block() {
+        // given
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+        let list = ListView::new(builder, 2)
+            .scroll_axis(ScrollAxis::Horizontal)
+            .block(Block::new().borders(Borders::TOP))
+            .direction_aware_block(true);
+
+        // when
+        list.render(area, &mut buf, &mut ListState::default());
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["┌──┐", "│01│", "│  │"]));
+    }
+
+    #[test]
+    fn separators_between_horizontal_items() {
+        // given
+        let area = Rect::new(0, 0, 7, 2);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.select(Some(0));
+        let builder = ListBuilder::new(|c| {
+            let style = if c.is_selected {
+                Style::new().add_modifier(Modifier::BOLD)
+            } else {
+                Style::new()
+            };
+            (Line::styled(format!("T{}", c.index), style), 2)
+        });
+        let list = ListView::new(builder, 4)
+            .scroll_axis(ScrollAxis::Horizontal)
+            .style(Style::new().bg(Color::Blue))
+            .separator(Span::styled("│", Style::new().fg(Color::Red)));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+        state.next();
+
+        // then
+        let mut expected = Buffer::with_lines(["T0│T1│T", "  │  │ "]);
+        expected.set_style(area, Style::new().bg(Color::Blue));
+        expected.set_style(
+            Rect::new(0, 0, 2, 1),
+            Style::new().add_modifier(Modifier::BOLD),
+        );
+        for x in [2, 5] {
+            expected.set_style(Rect::new(x, 0, 1, 2), Style::new().fg(Color::Red));
+        }
+        assert_buffer_eq(buf, expected);
+        assert_eq!(state.selected, Some(1));
+        assert_eq!(state.item_at_position(Position::new(2, 0)), None);
+        assert_eq!(state.item_at_position(Position::new(3, 0)), Some(1));
+    }
+
+    #[test]
+    fn separator_cut_off_at_the_viewport_edge() {
+        // given
+        let area = Rect::new(0, 0, 8, 1);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("T{}", c.index)), 2));
+            ListView::new(builder, 4)
+                .scroll_axis(ScrollAxis::Horizontal)
+                .separator("│")
+                .render(area, &mut buf, state);
+            buf
+        };
+
+        // when
+        state.select(Some(2));
+        let buf = render(&mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["T0│T1│T2"]));
+        assert_eq!(state.scroll_offset_index(), 0);
+
+        // when
+        state.next();
+        let buf = render(&mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["T1│T2│T3"]));
+        assert_eq!(state.selected, Some(3));
+
+        // when
+        state.previous();
+        let buf = render(&mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["T1│T2│T3"]));
+        assert_eq!(state.selected, Some(2));
+    }
+
+    #[test]
+    fn gutter() {
+        // given
+        let area = Rect::new(0, 0, 7, 6);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let list = ListView::new(ListBuilder::new(|_| (TestItem {}, 3)), 2).gutter(2, |i, s| {
+            let marker = if s { ">" } else { " " };
+            Line::from(format!("{marker}{i}"))
+        });
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                " 0┌───┐",
+                "  │   │",
+                "  └───┘",
+                ">1┌───┐",
+                "  │   │",
+                "  └───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn shortcut_hints() {
+        // given
+        let area = Rect::new(0, 0, 5, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.set_number_shortcuts();
+        state.remove_shortcut('2');
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+        let list = ListView::new(builder, 3)
+            .gutter(1, |_, s| Line::from(if s { ">" } else { " " }))
+            .shortcut_hints(true);
+
+        // when
+        state.activate_shortcut('3');
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["1  0 ", "   1 ", "3 >2 "]));
+    }
+
+    #[test]
+    fn build_budget() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ListState::default();
+        state.mark_loaded(0..10);
+        let builds = RefCell::new(Vec::new());
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| {
+                builds.borrow_mut().push(c.index);
+                (Line::from(format!("{}", c.index)), 1)
+            });
+            let list = ListView::new(builder, 10)
+                .build_budget(2)
+                .placeholder(1, |_, _| Line::from("~"));
+            list.render(area, &mut buf, state);
+            buf
+        };
+
+        // when
+        let buf = render(&mut state);
+
+        // then
+        assert!(!state.render_complete());
+        assert_eq!(*builds.borrow(), vec![0, 1]);
+        assert_buffer_eq(buf, Buffer::with_lines(["0  ", "1  ", "~  "]));
+
+        // when
+        let buf = render(&mut state);
+
+        // then
+        assert!(state.render_complete());
+        assert_buffer_eq(buf, Buffer::with_lines(["0  ", "1  ", "2  "]));
+    }
+
+    #[test]
+    fn remaining_main_axis_size() {
+        // given
+        let area = Rect::new(0, 0, 5, 7);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| match context.index {
+            0 => (TestItem {}, 3),
+            _ => (TestItem {}, context.remaining_main_axis_size.unwrap_or(3)),
+        });
+        let list = ListView::new(builder, 2);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn header() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(5);
+        let list = list.header(Line::from("Head"), 1);
+        state.select(Some(1));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec!["Head ", "└───┘", "┌───┐", "│   │", "└───┘"]),
+        )
+    }
+
+    #[test]
+    fn item_style() {
+        // given
+        let area = Rect::new(0, 0, 5, 2);
+        let mut state = ListState::default();
+        let style = Style::default().bg(ratatui::style::Color::Red);
+        state.set_item_style_for(1, style, 1);
+        let list = || ListView::new(ListBuilder::new(|_| (Line::from("Item"), 1)), 2);
+
+        // when
+        let mut buf = Buffer::empty(area);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(vec!["Item ", "Item "]);
+        expected.set_style(Rect::new(0, 1, 5, 1), style);
+        assert_buffer_eq(buf, expected);
+        assert_eq!(state.item_style(1), None);
+    }
+
+    #[test]
+    fn item_style_expires_while_zoomed() {
+        // given
+        let area = Rect::new(0, 0, 5, 2);
+        let mut state = ListState::default();
+        state.select(Some(0));
+        state.set_item_style_for(0, Style::default().bg(Color::Red), 2);
+        let list = || ListView::new(ListBuilder::new(|_| (Line::from("Item"), 1)), 2);
+
+        // when
+        state.zoom_selected(true);
+        for _ in 0..2 {
+            list().render(area, &mut Buffer::empty(area), &mut state);
+        }
+
+        // then
+        assert_eq!(state.item_style(0), None);
+    }
+
+    #[test]
+    fn flash() {
+        // given
+        let area = Rect::new(0, 0, 5, 1);
+        let mut state = ListState::default();
+        let base = Style::default().bg(Color::Rgb(0, 0, 0));
+        state.flash(0, Style::default().bg(Color::Rgb(100, 200, 0)), 4);
+        let list = || ListView::new(ListBuilder::new(|_| (Line::from("Item"), 1)), 1).style(base);
+
+        // when
+        let mut bgs = Vec::new();
+        for _ in 0..5 {
+            let mut buf = Buffer::empty(area);
+            list().render(area, &mut buf, &mut state);
+            bgs.push(buf[(0, 0)].bg);
+        }
+
+        // then
+        assert_eq!(
+            bgs,
+            vec![
+                Color::Rgb(100, 200, 0),
+                Color::Rgb(75, 150, 0),
+                Color::Rgb(50, 100, 0),
+                Color::Rgb(25, 50, 0),
+                Color::Rgb(0, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn items_fitting_in() {
+        // given
+        let builder = ListBuilder::new(|context| (TestItem {}, context.index as u16 + 1));
+        let list = ListView::new(builder, 4);
+
+        // then
+        assert_eq!(list.items_fitting_in(5, 5, 0), 2);
+        assert_eq!(list.items_fitting_in(6, 5, 0), 3);
+        assert_eq!(list.items_fitting_in(6, 5, 1), 2);
+        assert_eq!(list.items_fitting_in(100, 5, 2), 2);
+        assert_eq!(list.items_fitting_in(0, 5, 0), 0);
+    }
+
+    #[test]
+    fn expanded() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(8);
+        let list = list.expanded(|i| (Line::from(format!("{i}")), 1));
+        state.select(Some(1));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "1    ",
+                "┌───┐",
+            ]),
+        )
+    }
+
+    #[test]
+    fn expansion_frames() {
+        // given
+        let area = Rect::new(0, 0, 3, 6);
+        let mut state = ListState::default();
+        state.select(Some(0));
+        let list = || {
+            ListView::new(
+                ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1)),
+                2,
+            )
+            .expanded(|_| {
+                (
+                    ratatui::text::Text::from(vec![Line::from("a"), Line::from("b")]),
+                    4,
+                )
+            })
+            .expansion_frames(2)
+        };
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            list().render(area, &mut buf, state);
+            buf
+        };
+
+        // then
+        let buf = render(&mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0  ", "a  ", "b  ", "1  ", "   ", "   "]),
+        );
+        let buf = render(&mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0  ", "a  ", "b  ", "   ", "   ", "1  "]),
+        );
+
+        // when
+        state.next();
+
+        // then
+        let buf = render(&mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0  ", "a  ", "b  ", "1  ", "a  ", "b  "]),
+        );
+        let buf = render(&mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0  ", "1  ", "a  ", "b  ", "   ", "   "]),
+        );
+    }
+
+    #[test]
+    fn resize_selected() {
+        // given
+        let area = Rect::new(0, 0, 3, 6);
+        let mut state = ListState::default();
+        state.select(Some(0));
+        let render = |state: &mut ListState| {
+            let builder =
+                ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 2)).size_bounds(1, 3);
+            let mut buf = Buffer::empty(area);
+            ListView::new(builder, 3).render(area, &mut buf, state);
+            buf
+        };
+
+        // when
+        state.resize_selected(5);
+        let buf = render(&mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0  ", "   ", "   ", "1  ", "   ", "2  "]),
+        );
+
+        // when
+        state.resize_selected(-2);
+        let buf = render(&mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0  ", "1  ", "   ", "2  ", "   ", "   "]),
+        );
+    }
+
+    #[test]
+    fn min_partial_size() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(7);
+        let list = list.min_partial_size(2);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "     ",
+            ]),
+        );
+    }
+
+    #[test]
+    fn truncation_policy_hide() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(8);
+        let list = list.truncation_policy(TruncationPolicy::Hide);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        let indices: Vec<usize> = state.visible_indices().collect();
+        assert_eq!(indices, vec![0, 1]);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "     ",
+                "     ",
+            ]),
+        );
+    }
+
+    #[test]
+    fn truncation_policy_shrink() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(8);
+        let list = list.truncation_policy(TruncationPolicy::Shrink);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "└───┘",
+            ]),
+        );
+    }
+
+    #[test]
+    fn truncation_policy_shrink_passes_visible_size() {
+        // given
+        let area = Rect::new(0, 0, 9, 8);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| {
+            let line = Line::from(format!(
+                "{}:{:?}",
+                context.index, context.remaining_main_axis_size
+            ));
+            (line, 3)
+        })
+        .positional();
+        let list = ListView::new(builder, 4).truncation_policy(TruncationPolicy::Shrink);
+
+        // when
+        state.select(Some(3));
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "1:Some(2)",
+                "         ",
+                "2:Some(6)",
+                "         ",
+                "         ",
+                "3:Some(3)",
+                "         ",
+                "         ",
+            ]),
+        );
+    }
+
+    #[test]
+    fn main_axis_alignment() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(11);
+        let list = list.main_axis_alignment(MainAxisAlignment::Center);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "     ",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "     ",
+            ]),
+        );
+    }
+
+    #[test]
+    fn size_classes() {
+        // given
+        let render = |width: u16| {
+            let area = Rect::new(0, 0, width, 1);
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{:?}", c.size_class)), 1));
+            ListView::new(builder, 1)
+                .size_classes(&[(10, 2), (6, 1)])
+                .render(area, &mut buf, &mut ListState::default());
+            buf
+        };
+
+        // then
+        assert_buffer_eq(render(4), Buffer::with_lines(["None"]));
+        assert_buffer_eq(render(8), Buffer::with_lines(["Some(1) "]));
+        assert_buffer_eq(render(10), Buffer::with_lines(["Some(2)   "]));
+    }
+
+    #[test]
+    fn right_to_left() {
+        // given
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 2));
+        let list = ListView::new(builder, 3)
+            .scroll_axis(ScrollAxis::Horizontal)
+            .right_to_left(true);
+
+        // when
+        list.render(area, &mut buf, &mut ListState::default());
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines([" 1 0 "]));
+    }
+
+    #[test]
+    fn truncated_item_inherits_block_style() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut buf = Buffer::empty(area);
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 2));
+        let list = ListView::new(builder, 2).block(Block::new().style(Style::new().bg(Color::Red)));
+
+        // when
+        list.render(area, &mut buf, &mut ListState::default());
+
+        // then
+        assert_eq!(buf[(0, 2)].symbol(), "1");
+        assert_eq!(buf[(1, 2)].bg, Color::Red);
+    }
+
+    #[test]
+    fn base_style_under_truncation() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut buf = Buffer::empty(area);
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 2));
+        let list = ListView::new(builder, 2)
+            .block(Block::new().style(Style::new().bg(Color::Red)))
+            .base_style_under_truncation(Style::new().bg(Color::Blue));
+
+        // when
+        list.render(area, &mut buf, &mut ListState::default());
+
+        // then
+        assert_eq!(buf[(1, 1)].bg, Color::Red);
+        assert_eq!(buf[(1, 2)].bg, Color::Blue);
+    }
+
+    #[test]
+    fn selection_filter() {
+        // given
+        let area = Rect::new(0, 0, 3, 5);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 5)
+                .selection_filter(|from, to| from != Some(3) && to.is_none_or(|i| i % 2 == 0))
+                .render(area, &mut buf, state);
+        };
+        render(&mut state);
+
+        // when
+        state.next();
+        render(&mut state);
+        state.next();
+        render(&mut state);
+
+        // then
+        assert_eq!(state.selected, Some(2));
+
+        // when
+        state.select(Some(3));
+        render(&mut state);
+
+        // then
+        assert_eq!(state.selected, Some(2));
+
+        // when
+        state.previous();
+        render(&mut state);
+
+        // then
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn context_items() {
+        // given
+        let area = Rect::new(0, 0, 3, 2);
+        let mut state = ListState::default();
+        state.select(Some(5));
+        let built = std::cell::RefCell::new(std::collections::BTreeSet::new());
+        let builder = ListBuilder::new(|c| {
+            built.borrow_mut().insert(c.index);
+            (Line::from(format!("{}", c.index)), 1)
+        });
+        let list = ListView::new(builder, 10).context_items(2);
+
+        // when
+        list.render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        let built = built.borrow();
+        assert!((3..=7).all(|index| built.contains(&index)));
+        assert!(!built.contains(&8));
+    }
+
+    #[test]
+    fn index_at_offset() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(7);
+        let list = list.block(Block::new().borders(Borders::TOP));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(state.index_at_offset(0), Some(0));
+        assert_eq!(state.index_at_offset(2), Some(0));
+        assert_eq!(state.index_at_offset(3), Some(1));
+        assert_eq!(state.index_at_offset(6), None);
+    }
+
+    #[test]
+    fn placeholder() {
+        // given
+        let area = Rect::new(0, 0, 3, 4);
+        let mut state = ListState::default();
+        state.mark_loaded(0..2);
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 4)
+                .placeholder(1, |_, tick| Line::from(format!("~{tick}")))
+                .render(area, &mut buf, state);
+            buf
+        };
+
+        // then
+        let buf = render(&mut state);
+        assert_buffer_eq(buf, Buffer::with_lines(["0  ", "1  ", "~1 ", "~1 "]));
+
+        // when
+        state.mark_loaded(2..3);
+
+        // then
+        let buf = render(&mut state);
+        assert_buffer_eq(buf, Buffer::with_lines(["0  ", "1  ", "2  ", "~2 "]));
+    }
+
+    #[test]
+    fn tooltip() {
+        // given
+        let area = Rect::new(0, 0, 5, 4);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 4).render(area, &mut buf, state);
+            buf
+        };
+
+        // when
+        state.show_tooltip(1, "tip");
+
+        // then
+        let buf = render(&mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0    ", "1    ", "tip  ", "3    "]),
+        );
+
+        // when
+        state.show_tooltip(3, "tip");
+
+        // then
+        let buf = render(&mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0    ", "1    ", "tip  ", "3    "]),
         );
-        for i in start..end {
-            let Some(element) = viewport.remove(&i) else {
-                break;
-            };
-            let visible_main_axis_size = element
-                .main_axis_size
-                .saturating_sub(element.truncation.value());
-            let area = match self.scroll_axis {
-                ScrollAxis::Vertical => Rect::new(
-                    cross_axis_pos,
-                    scroll_axis_pos,
-                    cross_axis_size,
-                    visible_main_axis_size,
-                ),
-                ScrollAxis::Horizontal => Rect::new(
-                    scroll_axis_pos,
-                    cross_axis_pos,
-                    visible_main_axis_size,
-                    cross_axis_size,
-                ),
-            };
 
-            // Render truncated widgets.
-            if element.truncation.value() > 0 {
-                render_truncated(
-                    element.widget,
-                    area,
-                    buf,
-                    element.main_axis_size,
-                    &element.truncation,
-                    self.style,
-                    self.scroll_axis,
-                );
-            } else {
-                element.widget.render(area, buf);
-            }
+        // when
+        state.hide_tooltip();
 
-            scroll_axis_pos += visible_main_axis_size;
-        }
+        // then
+        let buf = render(&mut state);
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0    ", "1    ", "2    ", "3    "]),
+        );
     }
-}
 
-/// Render a truncated widget into a buffer. The method renders the widget fully into
-/// a hidden buffer and moves the visible content into `buf`.
-fn render_truncated<T: Widget>(
-    item: T,
-    available_area: Rect,
-    buf: &mut Buffer,
-    untruncated_size: u16,
-    truncation: &Truncation,
-    base_style: Style,
-    scroll_axis: ScrollAxis,
-) {
-    // Create an hidden buffer for rendering the truncated element
-    let (width, height) = match scroll_axis {
-        ScrollAxis::Vertical => (available_area.width, untruncated_size),
-        ScrollAxis::Horizontal => (untruncated_size, available_area.height),
-    };
-    let mut hidden_buffer = Buffer::empty(Rect {
-        x: available_area.left(),
-        y: available_area.top(),
-        width,
-        height,
-    });
-    hidden_buffer.set_style(hidden_buffer.area, base_style);
-    item.render(hidden_buffer.area, &mut hidden_buffer);
+    #[test]
+    fn editor() {
+        // given
+        let area = Rect::new(0, 0, 5, 3);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+            ListView::new(builder, 3)
+                .editor(Line::from("edit"))
+                .render(area, &mut buf, state);
+            buf
+        };
 
-    // Copy the visible part from the hidden buffer to the main buffer
-    match scroll_axis {
-        ScrollAxis::Vertical => {
-            let offset = match truncation {
-                Truncation::Top(value) => *value,
-                _ => 0,
-            };
-            for y in available_area.top()..available_area.bottom() {
-                let y_off = y + offset;
-                for x in available_area.left()..available_area.right() {
-                    if let Some(to) = buf.cell_mut(Position::new(x, y)) {
-                        if let Some(from) = hidden_buffer.cell(Position::new(x, y_off)) {
-                            *to = from.clone();
-                        }
-                    }
-                }
-            }
-        }
-        ScrollAxis::Horizontal => {
-            let offset = match truncation {
-                Truncation::Top(value) => *value,
-                _ => 0,
-            };
-            for x in available_area.left()..available_area.right() {
-                let x_off = x + offset;
-                for y in available_area.top()..available_area.bottom() {
-                    if let Some(to) = buf.cell_mut(Position::new(x, y)) {
-                        if let Some(from) = hidden_buffer.cell(Position::new(x_off, y)) {
-                            *to = from.clone();
-                        }
-                    }
-                }
-            }
-        }
-    };
-}
+        // when
+        state.start_editing();
+        state.next();
 
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Eq, Ord)]
-pub(crate) enum Truncation {
-    #[default]
-    None,
-    Top(u16),
-    Bot(u16),
-}
+        // then
+        assert_eq!(state.selected, Some(1));
+        let buf = render(&mut state);
+        assert_buffer_eq(buf, Buffer::with_lines(["0    ", "edit ", "2    "]));
 
-impl Truncation {
-    pub(crate) fn value(&self) -> u16 {
-        match self {
-            Self::Top(value) | Self::Bot(value) => *value,
-            Self::None => 0,
-        }
+        // when
+        state.stop_editing();
+        state.next();
+
+        // then
+        let buf = render(&mut state);
+        assert_buffer_eq(buf, Buffer::with_lines(["0    ", "1    ", "2    "]));
     }
-}
 
-#[cfg(test)]
-mod test {
-    use crate::ListBuilder;
-    use ratatui::widgets::Block;
+    #[test]
+    fn resize_keeps_first_item_anchored() {
+        // given
+        let mut state = ListState::default();
+        let render = |state: &mut ListState, height: u16| {
+            let area = Rect::new(0, 0, 2, height);
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| {
+                let text = Text::from(vec![
+                    Line::from(format!("{}a", c.index)),
+                    Line::from(format!("{}b", c.index)),
+                ]);
+                (text, 2)
+            });
+            ListView::new(builder, 10).render(area, &mut buf, state);
+            buf
+        };
+        for index in 0..3 {
+            state.select(Some(index));
+            render(&mut state, 5);
+        }
 
-    use super::*;
-    use ratatui::widgets::Borders;
+        // when
+        let buf = render(&mut state, 6);
 
-    struct TestItem {}
-    impl Widget for TestItem {
-        fn render(self, area: Rect, buf: &mut Buffer)
-        where
-            Self: Sized,
-        {
-            Block::default().borders(Borders::ALL).render(area, buf);
-        }
-    }
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0a", "0b", "1a", "1b", "2a", "2b"]),
+        );
 
-    fn test_data(total_height: u16) -> (Rect, Buffer, ListView<'static, TestItem>, ListState) {
-        let area = Rect::new(0, 0, 5, total_height);
-        let list = ListView::new(ListBuilder::new(|_| (TestItem {}, 3)), 3);
-        (area, Buffer::empty(area), list, ListState::default())
+        // when
+        state.select(Some(1));
+        let buf = render(&mut state, 4);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["0a", "0b", "1a", "1b"]));
+
+        // when
+        state.select(Some(2));
+        let buf = render(&mut state, 3);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["1b", "2a", "2b"]));
     }
 
     #[test]
-    fn not_truncated() {
+    fn viewport_index() {
         // given
-        let (area, mut buf, list, mut state) = test_data(9);
+        let area = Rect::new(0, 0, 9, 3);
+        let mut state = ListState::default();
+        let render = |state: &mut ListState| {
+            let mut buf = Buffer::empty(area);
+            let builder = ListBuilder::new(|c| {
+                let line = Line::from(format!("{}:{:?}", c.index, c.viewport_index));
+                (line, 1)
+            })
+            .positional();
+            ListView::new(builder, 10).render(area, &mut buf, state);
+            buf
+        };
 
         // when
-        list.render(area, &mut buf, &mut state);
+        state.select(Some(1));
+        let buf = render(&mut state);
 
         // then
         assert_buffer_eq(
             buf,
-            Buffer::with_lines(vec![
-                "┌───┐",
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-            ]),
-        )
+            Buffer::with_lines(["0:Some(0)", "1:Some(1)", "2:Some(2)"]),
+        );
+
+        // when
+        state.select(Some(4));
+        let buf = render(&mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["2:Some(0)", "3:Some(1)", "4:Some(2)"]),
+        );
+
+        // when
+        state.select(Some(0));
+        let buf = render(&mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(["0:Some(0)", "1:Some(1)", "2:Some(2)"]),
+        );
     }
 
     #[test]
-    fn empty_list() {
+    fn backward_pass_builds_items_once() {
         // given
-        let area = Rect::new(0, 0, 5, 2);
-        let mut buf = Buffer::empty(area);
+        let area = Rect::new(0, 0, 5, 3);
         let mut state = ListState::default();
-        let builder = ListBuilder::new(|_| (TestItem {}, 0));
-        let list = ListView::new(builder, 0);
+        let builds = RefCell::new(Vec::new());
+        let render = |state: &mut ListState, positional: bool| {
+            let builder = ListBuilder::new(|c| {
+                builds.borrow_mut().push(c.index);
+                (TestItem {}, 1)
+            });
+            let builder = if positional {
+                builder.positional()
+            } else {
+                builder
+            };
+            ListView::new(builder, 10).render(area, &mut Buffer::empty(area), state);
+        };
 
         // when
-        list.render(area, &mut buf, &mut state);
+        state.select(Some(6));
+        render(&mut state, false);
 
         // then
-        assert_buffer_eq(buf, Buffer::with_lines(vec!["     ", "     "]))
+        assert_eq!(*builds.borrow(), vec![0, 1, 2, 6, 5, 4]);
+
+        // when
+        builds.borrow_mut().clear();
+        state.select(Some(9));
+        render(&mut state, true);
+
+        // then
+        assert_eq!(*builds.borrow(), vec![4, 5, 6, 9, 8, 7, 7, 8, 9]);
     }
 
     #[test]
-    fn zero_size() {
+    fn dimmed() {
         // given
-        let (area, mut buf, list, mut state) = test_data(0);
+        let area = Rect::new(0, 0, 3, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let builder = ListBuilder::new(|c| (Line::from(format!("{}", c.index)), 1));
+        let list = ListView::new(builder, 3).dimmed(Style::new().add_modifier(Modifier::DIM));
 
         // when
         list.render(area, &mut buf, &mut state);
 
         // then
-        assert_buffer_eq(buf, Buffer::empty(area))
+        let mut expected = Buffer::with_lines(["0  ", "1  ", "2  "]);
+        expected.set_style(
+            Rect::new(0, 0, 3, 1),
+            Style::new().add_modifier(Modifier::DIM),
+        );
+        expected.set_style(
+            Rect::new(0, 2, 3, 1),
+            Style::new().add_modifier(Modifier::DIM),
+        );
+        assert_buffer_eq(buf, expected);
     }
 
     #[test]
-    fn truncated_bot() {
+    fn joined_borders() {
         // given
-        let (area, mut buf, list, mut state) = test_data(8);
+        let area = Rect::new(0, 0, 3, 8);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|_| (Block::bordered(), 3));
+        let list = ListView::new(builder, 3).joined_borders(true);
 
         // when
         list.render(area, &mut buf, &mut state);
@@ -442,81 +4394,42 @@ mod test {
         // then
         assert_buffer_eq(
             buf,
-            Buffer::with_lines(vec![
-                "┌───┐",
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-            ]),
-        )
+            Buffer::with_lines(["┌─┐", "│ │", "├─┤", "│ │", "├─┤", "│ │", "└─┘", "   "]),
+        );
     }
 
     #[test]
-    fn truncated_top() {
+    fn joined_borders_horizontal() {
         // given
-        let (area, mut buf, list, mut state) = test_data(8);
-        state.select(Some(2));
+        let area = Rect::new(0, 0, 6, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|_| (Block::bordered(), 3));
+        let list = ListView::new(builder, 3)
+            .scroll_axis(ScrollAxis::Horizontal)
+            .joined_borders(true);
 
         // when
         list.render(area, &mut buf, &mut state);
 
         // then
-        assert_buffer_eq(
-            buf,
-            Buffer::with_lines(vec![
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-            ]),
-        )
+        assert_buffer_eq(buf, Buffer::with_lines(["┌─┬─┬─", "│ │ │ ", "└─┴─┴─"]));
     }
 
     #[test]
-    fn scroll_up() {
-        let (area, mut buf, list, mut state) = test_data(8);
-        // Select last element and render
-        state.select(Some(2));
-        list.render(area, &mut buf, &mut state);
-        assert_buffer_eq(
-            buf,
-            Buffer::with_lines(vec![
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-            ]),
-        );
+    fn from_slice_borrows_items() {
+        // given
+        let area = Rect::new(0, 0, 3, 2);
+        let mut buf = Buffer::empty(area);
+        let items = vec![String::from("ab"), String::from("cd")];
+        let builder = ListBuilder::from_slice(&items, |item, _| (Line::from(item.as_str()), 1));
+        let list = ListView::new(builder, items.len());
 
-        // Select first element and render
-        let (_, mut buf, list, _) = test_data(8);
-        state.select(Some(1));
-        list.render(area, &mut buf, &mut state);
-        assert_buffer_eq(
-            buf,
-            Buffer::with_lines(vec![
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-                "┌───┐",
-                "│   │",
-                "└───┘",
-            ]),
-        )
+        // when
+        list.render(area, &mut buf, &mut ListState::default());
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(["ab ", "cd "]));
     }
 
     fn assert_buffer_eq(actual: Buffer, expected: Buffer) {
@@ -0,0 +1,178 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled, Stylize},
+    text::{Line, Span},
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{units, ListBuilder, ListState, ListView, ScrollAxis};
+
+/// A breadcrumb trail that renders path segments on a horizontal [`ListView`].
+///
+/// If the segments do not fit, the middle segments are collapsed into an
+/// ellipsis, such that the first segment and as many of the last segments as
+/// possible remain visible. If even those do not fit, the list truncates the
+/// trail at the start, so that the current segment stays in view.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+/// use tui_widget_list::BreadcrumbView;
+///
+/// let area = Rect::new(0, 0, 19, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// BreadcrumbView::new(["home", "user", "projects", "docs"])
+///     .current_style(Style::new())
+///     .render(area, &mut buf);
+///
+/// assert_eq!(buf, Buffer::with_lines([" home › … › docs   "]));
+/// ```
+pub struct BreadcrumbView<'a> {
+    /// The segments of the path.
+    segments: Vec<Line<'a>>,
+
+    /// The separator between adjacent segments.
+    separator: Span<'a>,
+
+    /// The base style of the widget.
+    style: Style,
+
+    /// The style of the last segment.
+    current_style: Style,
+}
+
+impl<'a> BreadcrumbView<'a> {
+    /// Creates a new `BreadcrumbView` from the segments of a path.
+    #[must_use]
+    pub fn new<I, L>(segments: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Line<'a>>,
+    {
+        Self {
+            segments: segments.into_iter().map(Into::into).collect(),
+            separator: Span::raw("›"),
+            style: Style::default(),
+            current_style: Style::default().bold(),
+        }
+    }
+
+    /// Sets the separator between adjacent segments. Defaults to `›`.
+    /// See [`ListView::separator`].
+    #[must_use]
+    pub fn separator<S: Into<Span<'a>>>(mut self, separator: S) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the style of the last segment, i.e. the current location.
+    /// Defaults to bold.
+    #[must_use]
+    pub fn current_style<S: Into<Style>>(mut self, current_style: S) -> Self {
+        self.current_style = current_style.into();
+        self
+    }
+
+    /// Returns the indices of the segments that fit into `width`, where `None`
+    /// stands for the ellipsis of the collapsed middle segments.
+    fn visible_segments(&self, width: u16) -> Vec<Option<usize>> {
+        // Each segment is padded on both sides and followed by a separator.
+        let size = |index: usize| {
+            usize::from(units::saturating_u16(self.segments[index].width()).saturating_add(3))
+        };
+        let count = self.segments.len();
+        let total: usize = (0..count).map(size).sum();
+        if total <= usize::from(width) + 1 || count < 3 {
+            return (0..count).map(Some).collect();
+        }
+
+        // Keep the first segment, the ellipsis and the last segment, then add
+        // the segments in front of the last one while they fit.
+        let ellipsis = 4;
+        let mut used = size(0) + ellipsis + size(count - 1);
+        let mut first_tail = count - 1;
+        while first_tail > 1 && used + size(first_tail - 1) <= usize::from(width) + 1 {
+            first_tail -= 1;
+            used += size(first_tail);
+        }
+        let mut visible = vec![Some(0), None];
+        visible.extend((first_tail..count).map(Some));
+        visible
+    }
+}
+
+impl Styled for BreadcrumbView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for BreadcrumbView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        if area.is_empty() || self.segments.is_empty() {
+            return;
+        }
+
+        let visible = self.visible_segments(area.width);
+        let (segments, current_style) = (&self.segments, self.current_style);
+        let last = segments.len() - 1;
+        let builder = ListBuilder::new(|context| {
+            let mut spans = vec![Span::raw(" ")];
+            match visible[context.index] {
+                Some(index) => spans.extend(segments[index].spans.iter().cloned()),
+                None => spans.push(Span::raw("…")),
+            }
+            spans.push(Span::raw(" "));
+            let mut line = Line::from(spans);
+            if let Some(index) = visible[context.index] {
+                line = line.style(segments[index].style);
+                if index == last {
+                    line = line.patch_style(current_style);
+                }
+            }
+            let width = units::saturating_u16(line.width());
+            (line, width)
+        });
+
+        // Select the current segment, so that the trail is truncated at the start.
+        let mut state = ListState::default();
+        state.select(Some(visible.len() - 1));
+        ListView::new(builder, visible.len())
+            .scroll_axis(ScrollAxis::Horizontal)
+            .style(self.style)
+            .separator(self.separator)
+            .render(area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(width: u16) -> Buffer {
+        let area = Rect::new(0, 0, width, 1);
+        let mut buf = Buffer::empty(area);
+        BreadcrumbView::new(["a", "bb", "cc", "d"])
+            .current_style(Style::new())
+            .render(area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn collapses_middle_segments() {
+        assert_eq!(render(17), Buffer::with_lines([" a › bb › cc › d "]));
+        assert_eq!(render(16), Buffer::with_lines([" a › … › cc › d "]));
+        assert_eq!(render(12), Buffer::with_lines([" a › … › d  "]));
+        assert_eq!(render(5), Buffer::with_lines([" › d "]));
+    }
+}
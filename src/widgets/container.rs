@@ -0,0 +1,145 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    widgets::{Block, Padding, Widget},
+};
+
+/// A container that wraps a list item with padding, an optional block and a style.
+///
+/// # Example
+/// ```
+/// use ratatui::{style::{Color, Style, Stylize}, text::Line, widgets::Padding};
+/// use tui_widget_list::{ListBuilder, ListItemContainer};
+///
+/// let builder = ListBuilder::new(|context| {
+///     let line = Line::from(format!("Item {}", context.index));
+///     let item = ListItemContainer::new(line, Padding::vertical(1))
+///         .bg(Color::Black)
+///         .selected_style(Style::default().bg(Color::White))
+///         .selected(context.is_selected);
+///     (item, 3)
+/// });
+/// ```
+pub struct ListItemContainer<'a, W> {
+    /// The wrapped widget.
+    child: W,
+
+    /// The block surrounding the child widget.
+    block: Block<'a>,
+
+    /// The base style of the container.
+    style: Style,
+
+    /// The style that is applied on top of the base style if the item is selected.
+    selected_style: Style,
+
+    /// Whether the item is selected.
+    is_selected: bool,
+}
+
+impl<'a, W> ListItemContainer<'a, W> {
+    /// Creates a new `ListItemContainer` that wraps `child` with the given `padding`.
+    #[must_use]
+    pub fn new(child: W, padding: Padding) -> Self {
+        Self {
+            child,
+            block: Block::default().padding(padding),
+            style: Style::default(),
+            selected_style: Style::default(),
+            is_selected: false,
+        }
+    }
+
+    /// Sets the block that surrounds the child widget. This replaces the padding
+    /// given in [`ListItemContainer::new`].
+    #[must_use]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the style that is applied on top of the base style if the item is selected.
+    #[must_use]
+    pub fn selected_style<S: Into<Style>>(mut self, selected_style: S) -> Self {
+        self.selected_style = selected_style.into();
+        self
+    }
+
+    /// Specify whether the item is selected.
+    #[must_use]
+    pub fn selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = is_selected;
+        self
+    }
+}
+
+impl<T> Styled for ListItemContainer<'_, T> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<W: Widget> Widget for ListItemContainer<'_, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner_area = self.block.inner(area);
+        buf.set_style(area, self.style);
+        if self.is_selected {
+            buf.set_style(area, self.selected_style);
+        }
+        self.block.render(area, buf);
+        self.child.render(inner_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        style::{Color, Stylize},
+        text::Line,
+        widgets::Borders,
+    };
+
+    use super::*;
+
+    #[test]
+    fn padding() {
+        // given
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        let item = ListItemContainer::new(Line::from("ab"), Padding::uniform(1));
+
+        // when
+        item.render(area, &mut buf);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(vec!["    ", " ab ", "    "]));
+    }
+
+    #[test]
+    fn block_and_selected_style() {
+        // given
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        let item = ListItemContainer::new(Line::from("ab"), Padding::ZERO)
+            .block(Block::default().borders(Borders::ALL))
+            .bg(Color::Black)
+            .selected_style(Style::default().bg(Color::White))
+            .selected(true);
+
+        // when
+        item.render(area, &mut buf);
+
+        // then
+        let mut expected = Buffer::with_lines(vec!["┌──┐", "│ab│", "└──┘"]);
+        expected.set_style(area, Style::default().bg(Color::White));
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,77 @@
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// A widget that is either of two widget types.
+///
+/// This allows a [`ListBuilder`] to return an entirely different widget for some
+/// items, e.g. a detailed card for the selected item and a single line otherwise,
+/// without forcing a single item type to handle both modes.
+///
+/// # Example
+/// ```
+/// use ratatui::{text::{Line, Text}};
+/// use tui_widget_list::{Either, ListBuilder};
+///
+/// let builder = ListBuilder::new(|context| {
+///     if context.is_selected {
+///         let card = Text::from(vec![Line::from("Item"), Line::from("Details")]);
+///         (Either::Right(card), 2)
+///     } else {
+///         (Either::Left(Line::from("Item")), 1)
+///     }
+/// });
+/// ```
+///
+/// [`ListBuilder`]: crate::ListBuilder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<L, R> {
+    /// The first widget type.
+    Left(L),
+
+    /// The second widget type.
+    Right(R),
+}
+
+impl<L: Widget, R: Widget> Widget for Either<L, R> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self {
+            Self::Left(widget) => widget.render(area, buf),
+            Self::Right(widget) => widget.render(area, buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        text::Line,
+        widgets::{Block, Borders, StatefulWidget},
+    };
+
+    use super::*;
+    use crate::{ListBuilder, ListState, ListView};
+
+    #[test]
+    fn render_selected_as_other_widget() {
+        // given
+        let area = Rect::new(0, 0, 4, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let builder = ListBuilder::new(|context| {
+            if context.is_selected {
+                (Either::Right(Block::default().borders(Borders::ALL)), 3)
+            } else {
+                (Either::Left(Line::from("ab")), 1)
+            }
+        });
+
+        // when
+        ListView::new(builder, 2).render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["ab  ", "┌──┐", "│  │", "└──┘"])
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod breadcrumb;
+pub(crate) mod container;
+pub(crate) mod either;
+pub(crate) mod row;
+pub(crate) mod select;
+pub(crate) mod tabs;
+pub(crate) mod toast;
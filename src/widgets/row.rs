@@ -0,0 +1,174 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::units;
+
+/// A list item that renders left-aligned and right-aligned content in one row,
+/// e.g. a file name on the left and its size on the right.
+///
+/// # Example
+/// ```
+/// use tui_widget_list::{ListBuilder, RowItem};
+///
+/// let builder = ListBuilder::new(|context| {
+///     let item = RowItem::new(format!("file_{}.txt", context.index), "4 KB").gap(2);
+///     (item, 1)
+/// });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RowItem<'a> {
+    /// The left-aligned content.
+    left: Line<'a>,
+
+    /// The right-aligned content.
+    right: Line<'a>,
+
+    /// The minimum space between the left and the right content.
+    gap: u16,
+
+    /// The side that keeps its content if the row is too narrow.
+    priority: RowPriority,
+
+    /// The base style of the row.
+    style: Style,
+}
+
+/// Specifies which side of a [`RowItem`] keeps its content if the row is too
+/// narrow to fit both sides. The other side is truncated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RowPriority {
+    /// The left content is kept, the right content is truncated. This is the default.
+    #[default]
+    Left,
+
+    /// The right content is kept, the left content is truncated.
+    Right,
+}
+
+impl<'a> RowItem<'a> {
+    /// Creates a new `RowItem` from a left-aligned and a right-aligned content.
+    #[must_use]
+    pub fn new<L: Into<Line<'a>>, R: Into<Line<'a>>>(left: L, right: R) -> Self {
+        Self {
+            left: left.into(),
+            right: right.into(),
+            gap: 1,
+            priority: RowPriority::default(),
+            style: Style::default(),
+        }
+    }
+
+    /// Sets the minimum space between the left and the right content. Defaults to 1.
+    #[must_use]
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets which side keeps its content if the row is too narrow.
+    #[must_use]
+    pub fn priority(mut self, priority: RowPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl Styled for RowItem<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for RowItem<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+
+        let left_width = units::saturating_u16(self.left.width());
+        let right_width = units::saturating_u16(self.right.width());
+
+        // Determine the widths of both sides, truncating the side without priority.
+        let (left_width, right_width) = match self.priority {
+            RowPriority::Left => {
+                let left_width = left_width.min(area.width);
+                let available = area
+                    .width
+                    .saturating_sub(left_width.saturating_add(self.gap));
+                (left_width, right_width.min(available))
+            }
+            RowPriority::Right => {
+                let right_width = right_width.min(area.width);
+                let available = area
+                    .width
+                    .saturating_sub(right_width.saturating_add(self.gap));
+                (left_width.min(available), right_width)
+            }
+        };
+
+        let left_area = Rect {
+            width: left_width,
+            ..area
+        };
+        let right_area = Rect {
+            x: area.right() - right_width,
+            width: right_width,
+            ..area
+        };
+        self.left.render(left_area, buf);
+        self.right.render(right_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(item: RowItem, width: u16) -> Buffer {
+        let area = Rect::new(0, 0, width, 1);
+        let mut buf = Buffer::empty(area);
+        item.render(area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn fits() {
+        let buf = render(RowItem::new("left", "right"), 12);
+        assert_eq!(buf, Buffer::with_lines(vec!["left   right"]));
+    }
+
+    #[test]
+    fn truncate_right() {
+        let buf = render(RowItem::new("left", "right"), 8);
+        assert_eq!(buf, Buffer::with_lines(vec!["left rig"]));
+    }
+
+    #[test]
+    fn truncate_left() {
+        let item = RowItem::new("left", "right").priority(RowPriority::Right);
+        let buf = render(item, 8);
+        assert_eq!(buf, Buffer::with_lines(vec!["le right"]));
+    }
+
+    #[test]
+    fn large_gap() {
+        let buf = render(RowItem::new("left", "right").gap(u16::MAX), 8);
+        assert_eq!(buf, Buffer::with_lines(vec!["left    "]));
+
+        let item = RowItem::new("left", "right")
+            .gap(u16::MAX)
+            .priority(RowPriority::Right);
+        let buf = render(item, 8);
+        assert_eq!(buf, Buffer::with_lines(vec!["   right"]));
+    }
+}
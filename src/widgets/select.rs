@@ -0,0 +1,320 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled, Stylize},
+    text::{Line, Span},
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{ListBuilder, ListState, ListView};
+
+/// A type alias for the matcher closure.
+type Matcher<'a> = dyn Fn(&str, &str) -> bool + 'a;
+
+/// A searchable select widget that combines a one-line input with a filtered
+/// list of options below it.
+///
+/// The state machine (typing vs. navigating) is managed by [`SelectPromptState`].
+///
+/// # Example
+/// ```
+/// use ratatui::{prelude::*, widgets::StatefulWidget};
+/// use tui_widget_list::{SelectPrompt, SelectPromptState};
+///
+/// let mut state = SelectPromptState::default();
+/// state.insert_char('b');
+///
+/// let area = Rect::new(0, 0, 10, 4);
+/// let mut buf = Buffer::empty(area);
+/// SelectPrompt::new(["apple", "banana", "cherry"]).render(area, &mut buf, &mut state);
+///
+/// assert_eq!(state.selected_option(), Some(1));
+/// ```
+pub struct SelectPrompt<'a> {
+    /// The options to choose from.
+    options: Vec<String>,
+
+    /// Decides whether an option matches the query.
+    matcher: Box<Matcher<'a>>,
+
+    /// The prompt that is displayed in front of the query.
+    prompt: Line<'a>,
+
+    /// The base style of the widget.
+    style: Style,
+
+    /// The style of the selected option.
+    selected_style: Style,
+}
+
+/// The mode of a [`SelectPrompt`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SelectMode {
+    /// The user is typing the query. This is the default.
+    #[default]
+    Typing,
+
+    /// The user is navigating through the filtered options.
+    Navigating,
+}
+
+/// The state of a [`SelectPrompt`].
+#[derive(Debug, Default, Clone)]
+pub struct SelectPromptState {
+    /// The current query.
+    query: String,
+
+    /// The current mode.
+    mode: SelectMode,
+
+    /// The state of the filtered list.
+    list_state: ListState,
+
+    /// The indices of the options that match the query.
+    filtered: Vec<usize>,
+}
+
+impl<'a> SelectPrompt<'a> {
+    /// Creates a new `SelectPrompt` from a list of options.
+    #[must_use]
+    pub fn new<I, S>(options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            matcher: Box::new(fuzzy_match),
+            prompt: Line::from("> "),
+            style: Style::default(),
+            selected_style: Style::default().reversed(),
+        }
+    }
+
+    /// Sets the matcher that decides whether an option matches the query. The
+    /// closure receives the query and the option.
+    ///
+    /// By default, an option matches if it contains the characters of the query
+    /// in order, ignoring case.
+    #[must_use]
+    pub fn matcher<F>(mut self, matcher: F) -> Self
+    where
+        F: Fn(&str, &str) -> bool + 'a,
+    {
+        self.matcher = Box::new(matcher);
+        self
+    }
+
+    /// Sets the prompt that is displayed in front of the query. Defaults to `"> "`.
+    #[must_use]
+    pub fn prompt<L: Into<Line<'a>>>(mut self, prompt: L) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the style of the selected option. Defaults to reversed.
+    #[must_use]
+    pub fn selected_style<S: Into<Style>>(mut self, selected_style: S) -> Self {
+        self.selected_style = selected_style.into();
+        self
+    }
+}
+
+impl Styled for SelectPrompt<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl SelectPromptState {
+    /// Returns the current query.
+    #[must_use]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the current mode.
+    #[must_use]
+    pub fn mode(&self) -> SelectMode {
+        self.mode
+    }
+
+    /// Appends a character to the query and switches to typing mode.
+    pub fn insert_char(&mut self, c: char) {
+        self.query.push(c);
+        self.on_query_changed();
+    }
+
+    /// Removes the last character of the query and switches to typing mode.
+    pub fn delete_char(&mut self) {
+        self.query.pop();
+        self.on_query_changed();
+    }
+
+    /// Clears the query and switches to typing mode.
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.on_query_changed();
+    }
+
+    /// Selects the next matching option and switches to navigating mode.
+    pub fn next(&mut self) {
+        self.mode = SelectMode::Navigating;
+        self.list_state.next();
+    }
+
+    /// Selects the previous matching option and switches to navigating mode.
+    pub fn previous(&mut self) {
+        self.mode = SelectMode::Navigating;
+        self.list_state.previous();
+    }
+
+    /// Returns the index of the selected option in the list of all options.
+    /// Returns `None` if no option matches the query.
+    #[must_use]
+    pub fn selected_option(&self) -> Option<usize> {
+        self.list_state
+            .selected
+            .and_then(|index| self.filtered.get(index).copied())
+    }
+
+    /// Returns the indices of the options that matched the query in the last render.
+    #[must_use]
+    pub fn filtered_options(&self) -> &[usize] {
+        &self.filtered
+    }
+
+    /// Resets the selection to the first match after the query changed.
+    fn on_query_changed(&mut self) {
+        self.mode = SelectMode::Typing;
+        self.list_state.select(Some(0));
+    }
+}
+
+impl StatefulWidget for SelectPrompt<'_> {
+    type State = SelectPromptState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        if area.is_empty() {
+            return;
+        }
+
+        // Filter the options
+        state.filtered = (0..self.options.len())
+            .filter(|&index| (self.matcher)(&state.query, &self.options[index]))
+            .collect();
+        state.list_state.selected = match state.list_state.selected {
+            _ if state.filtered.is_empty() => None,
+            Some(index) if index < state.filtered.len() => Some(index),
+            _ => Some(0),
+        };
+
+        // Render the input line
+        let input_area = Rect { height: 1, ..area };
+        let mut input = self.prompt;
+        input.spans.push(Span::raw(state.query.clone()));
+        input.render(input_area, buf);
+
+        // Render the filtered options
+        let list_area = Rect {
+            y: area.y + 1,
+            height: area.height - 1,
+            ..area
+        };
+        let (options, filtered, selected_style) =
+            (&self.options, &state.filtered, self.selected_style);
+        let builder = ListBuilder::new(move |context| {
+            let mut line = Line::from(options[filtered[context.index]].as_str());
+            if context.is_selected {
+                line = line.style(selected_style);
+            }
+            (line, 1)
+        });
+        ListView::new(builder, state.filtered.len()).render(list_area, buf, &mut state.list_state);
+    }
+}
+
+/// Returns true if `option` contains the characters of `query` in order, ignoring case.
+fn fuzzy_match(query: &str, option: &str) -> bool {
+    let mut option_chars = option.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| option_chars.any(|o| o == q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(state: &mut SelectPromptState) -> Buffer {
+        let area = Rect::new(0, 0, 8, 4);
+        let mut buf = Buffer::empty(area);
+        SelectPrompt::new(["apple", "banana", "cherry"])
+            .selected_style(Style::default())
+            .render(area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn filter_and_navigate() {
+        // given
+        let mut state = SelectPromptState::default();
+
+        // when
+        state.insert_char('a');
+        let buf = render(&mut state);
+
+        // then
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["> a     ", "apple   ", "banana  ", "        "])
+        );
+        assert_eq!(state.selected_option(), Some(0));
+
+        // when
+        state.next();
+        render(&mut state);
+
+        // then
+        assert_eq!(state.mode(), SelectMode::Navigating);
+        assert_eq!(state.selected_option(), Some(1));
+
+        // when
+        state.insert_char('n');
+        render(&mut state);
+
+        // then
+        assert_eq!(state.mode(), SelectMode::Typing);
+        assert_eq!(state.filtered_options(), &[1]);
+        assert_eq!(state.selected_option(), Some(1));
+    }
+
+    #[test]
+    fn no_match() {
+        // given
+        let mut state = SelectPromptState::default();
+
+        // when
+        state.insert_char('x');
+        render(&mut state);
+
+        // then
+        assert_eq!(state.selected_option(), None);
+    }
+
+    #[test]
+    fn fuzzy() {
+        assert!(fuzzy_match("bna", "Banana"));
+        assert!(fuzzy_match("", "apple"));
+        assert!(!fuzzy_match("ab", "banana"));
+    }
+}
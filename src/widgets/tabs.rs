@@ -0,0 +1,258 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled, Stylize},
+    text::{Line, Span},
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{layout::Truncation, units, ListBuilder, ListState, ListView, ScrollAxis};
+
+/// A tab bar that renders labels on a horizontal [`ListView`].
+///
+/// The bar is driven by a plain [`ListState`], so tabs are selected with
+/// [`ListState::next`], [`ListState::previous`] or [`ListState::select`], and
+/// the bar scrolls horizontally to keep the selected tab in view. If the tabs
+/// do not fit, chevrons at the edges indicate the hidden tabs.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::StatefulWidget};
+/// use tui_widget_list::{ListState, TabBarView};
+///
+/// let area = Rect::new(0, 0, 21, 1);
+/// let mut buf = Buffer::empty(area);
+/// let mut state = ListState::default();
+/// state.select(Some(0));
+///
+/// TabBarView::new(["Files", "Edit", "View"])
+///     .selected_style(Style::new())
+///     .divider("│")
+///     .render(area, &mut buf, &mut state);
+///
+/// assert_eq!(buf, Buffer::with_lines([" Files │ Edit │ View "]));
+/// ```
+pub struct TabBarView<'a> {
+    /// The labels of the tabs.
+    labels: Vec<Line<'a>>,
+
+    /// How the widths of the tabs are determined.
+    tab_width: TabWidth,
+
+    /// The divider between adjacent tabs.
+    divider: Option<Span<'a>>,
+
+    /// Whether chevrons indicate hidden tabs.
+    chevrons: bool,
+
+    /// The base style of the widget.
+    style: Style,
+
+    /// The style of the selected tab.
+    selected_style: Style,
+}
+
+/// How the widths of the tabs of a [`TabBarView`] are determined.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TabWidth {
+    /// Each tab is as wide as its label plus one cell of padding on both sides.
+    /// This is the default.
+    #[default]
+    Content,
+
+    /// All tabs are as wide as the widest label plus padding, and the labels
+    /// are centered.
+    Equal,
+}
+
+impl<'a> TabBarView<'a> {
+    /// Creates a new `TabBarView` from the labels of the tabs.
+    #[must_use]
+    pub fn new<I, L>(labels: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Line<'a>>,
+    {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+            tab_width: TabWidth::Content,
+            divider: None,
+            chevrons: true,
+            style: Style::default(),
+            selected_style: Style::default().underlined(),
+        }
+    }
+
+    /// Sets how the widths of the tabs are determined. Defaults to
+    /// [`TabWidth::Content`].
+    #[must_use]
+    pub fn tab_width(mut self, tab_width: TabWidth) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Sets a divider between adjacent tabs, e.g. `│`. See [`ListView::separator`].
+    #[must_use]
+    pub fn divider<S: Into<Span<'a>>>(mut self, divider: S) -> Self {
+        self.divider = Some(divider.into());
+        self
+    }
+
+    /// Sets whether chevrons at the edges indicate tabs that do not fit onto
+    /// the bar. Defaults to `true`.
+    #[must_use]
+    pub fn chevrons(mut self, chevrons: bool) -> Self {
+        self.chevrons = chevrons;
+        self
+    }
+
+    /// Sets the style of the selected tab. Defaults to underlined.
+    #[must_use]
+    pub fn selected_style<S: Into<Style>>(mut self, selected_style: S) -> Self {
+        self.selected_style = selected_style.into();
+        self
+    }
+
+    /// Returns the width of each tab, including its padding.
+    fn widths(&self) -> Vec<u16> {
+        let widths = self
+            .labels
+            .iter()
+            .map(|label| units::saturating_u16(label.width()).saturating_add(2));
+        match self.tab_width {
+            TabWidth::Content => widths.collect(),
+            TabWidth::Equal => {
+                let width = widths.max().unwrap_or(0);
+                vec![width; self.labels.len()]
+            }
+        }
+    }
+}
+
+impl Styled for TabBarView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl StatefulWidget for TabBarView<'_> {
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        if area.is_empty() {
+            return;
+        }
+
+        // Reserve the edges for the chevrons if the tabs overflow the bar.
+        let widths = self.widths();
+        let dividers = if self.divider.is_some() {
+            widths.len().saturating_sub(1)
+        } else {
+            0
+        };
+        let total: usize = widths.iter().map(|&w| usize::from(w)).sum::<usize>() + dividers;
+        let overflows = self.chevrons && total > usize::from(area.width) && area.width > 2;
+        let list_area = if overflows {
+            Rect {
+                x: area.x + 1,
+                width: area.width - 2,
+                ..area
+            }
+        } else {
+            area
+        };
+
+        let (labels, centered, selected_style) = (
+            &self.labels,
+            self.tab_width == TabWidth::Equal,
+            self.selected_style,
+        );
+        let builder = ListBuilder::new(|context| {
+            let label = &labels[context.index];
+            let mut spans = vec![Span::raw(" ")];
+            spans.extend(label.spans.iter().cloned());
+            spans.push(Span::raw(" "));
+            let mut line = Line::from(spans).style(label.style);
+            if centered {
+                line = line.centered();
+            }
+            if context.is_selected {
+                line = line.patch_style(selected_style);
+            }
+            (line, widths[context.index])
+        });
+        let mut list = ListView::new(builder, self.labels.len())
+            .scroll_axis(ScrollAxis::Horizontal)
+            .style(self.style);
+        if let Some(divider) = self.divider {
+            list = list.separator(divider);
+        }
+        list.render(list_area, buf, state);
+
+        // Point the chevrons at the hidden tabs.
+        if overflows {
+            let (Some(first), Some(last)) = (state.item_spans.first(), state.item_spans.last())
+            else {
+                return;
+            };
+            let hidden_before = first.index > 0 || matches!(first.truncation, Truncation::Top(_));
+            let hidden_after =
+                last.index + 1 < self.labels.len() || matches!(last.truncation, Truncation::Bot(_));
+            if hidden_before {
+                Span::raw("‹").render(Rect { width: 1, ..area }, buf);
+            }
+            if hidden_after {
+                let x = area.right() - 1;
+                Span::raw("›").render(
+                    Rect {
+                        x,
+                        width: 1,
+                        ..area
+                    },
+                    buf,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chevrons_point_at_hidden_tabs() {
+        // given
+        let area = Rect::new(0, 0, 10, 1);
+        let mut state = ListState::default();
+        let tabs = || {
+            TabBarView::new(["a", "b", "c", "d"])
+                .tab_width(TabWidth::Equal)
+                .selected_style(Style::new())
+        };
+
+        // when
+        state.select(Some(0));
+        let mut buf = Buffer::empty(area);
+        tabs().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(["  a  b  c›"]));
+
+        // when
+        state.select(Some(3));
+        let mut buf = Buffer::empty(area);
+        tabs().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(["‹b  c  d  "]));
+    }
+}
@@ -0,0 +1,244 @@
+use std::{collections::VecDeque, time::Duration};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    text::Text,
+    widgets::{Block, Paragraph, StatefulWidget},
+};
+
+use crate::{units, ListBuilder, ListState, ListView, MainAxisAlignment};
+
+/// A stack of notifications that expire after a time to live.
+///
+/// The toasts are stored in a [`ToastState`]. New toasts are pushed onto the
+/// bottom of the stack and the viewport follows them, so the newest toast is
+/// always visible. At most [`ToastListView::max_visible`] of the newest toasts
+/// are rendered.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+/// use tui_widget_list::{ToastListView, ToastState};
+///
+/// let mut state = ToastState::new(Duration::from_secs(3));
+/// state.push("Saved");
+///
+/// let area = Rect::new(0, 0, 9, 4);
+/// let mut buf = Buffer::empty(area);
+/// ToastListView::new().render(area, &mut buf, &mut state);
+/// assert_eq!(
+///     buf,
+///     Buffer::with_lines(["         ", "┌───────┐", "│Saved  │", "└───────┘"])
+/// );
+///
+/// state.tick(Duration::from_secs(3));
+/// assert!(state.is_empty());
+/// ```
+pub struct ToastListView<'a> {
+    /// The block surrounding each toast.
+    block: Block<'a>,
+
+    /// The maximum number of toasts that are rendered.
+    max_visible: usize,
+
+    /// The base style of the widget.
+    style: Style,
+}
+
+/// The toasts of a [`ToastListView`].
+#[derive(Debug, Clone)]
+pub struct ToastState {
+    /// The toasts from the oldest to the newest.
+    toasts: VecDeque<Toast>,
+
+    /// The time to live of new toasts.
+    ttl: Duration,
+
+    /// The state of the list of rendered toasts.
+    list_state: ListState,
+}
+
+/// A notification of a [`ToastState`].
+#[derive(Debug, Clone)]
+struct Toast {
+    /// The content of the toast.
+    text: Text<'static>,
+
+    /// The time until the toast expires.
+    remaining: Duration,
+}
+
+impl Default for ToastListView<'_> {
+    fn default() -> Self {
+        Self {
+            block: Block::bordered(),
+            max_visible: 3,
+            style: Style::default(),
+        }
+    }
+}
+
+impl<'a> ToastListView<'a> {
+    /// Creates a new `ToastListView` that renders up to three bordered toasts.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the block that surrounds each toast. Defaults to a bordered block.
+    #[must_use]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the maximum number of toasts that are rendered. Older toasts are
+    /// hidden until newer ones expire. Defaults to three.
+    #[must_use]
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+}
+
+impl Styled for ToastListView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Default for ToastState {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(3))
+    }
+}
+
+impl ToastState {
+    /// Creates an empty stack whose toasts expire after `ttl`.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            toasts: VecDeque::new(),
+            ttl,
+            list_state: ListState::default(),
+        }
+    }
+
+    /// Pushes a new toast onto the stack.
+    pub fn push<T: Into<Text<'static>>>(&mut self, text: T) {
+        self.push_with_ttl(text, self.ttl);
+    }
+
+    /// Pushes a new toast onto the stack that expires after the given `ttl`
+    /// instead of the default time to live.
+    pub fn push_with_ttl<T: Into<Text<'static>>>(&mut self, text: T, ttl: Duration) {
+        self.toasts.push_back(Toast {
+            text: text.into(),
+            remaining: ttl,
+        });
+    }
+
+    /// Advances the time by `dt`, e.g. the time since the last frame, and
+    /// removes the expired toasts.
+    pub fn tick(&mut self, dt: Duration) {
+        for toast in &mut self.toasts {
+            toast.remaining = toast.remaining.saturating_sub(dt);
+        }
+        self.toasts.retain(|toast| !toast.remaining.is_zero());
+    }
+
+    /// Removes all toasts.
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Returns the number of toasts that have not expired yet.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Returns true if all toasts have expired.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+impl StatefulWidget for ToastListView<'_> {
+    type State = ToastState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let count = state.toasts.len().min(self.max_visible);
+        if area.is_empty() || count == 0 {
+            return;
+        }
+
+        // Only the newest toasts are rendered.
+        let toasts: Vec<&Toast> = state.toasts.range(state.toasts.len() - count..).collect();
+        let probe = Rect::new(0, 0, area.width, u16::MAX);
+        let border_size = u16::MAX - self.block.inner(probe).height;
+        let block = &self.block;
+        let builder = ListBuilder::new(|context| {
+            let text = toasts[context.index].text.clone();
+            let size = units::saturating_u16(text.height()).saturating_add(border_size);
+            (Paragraph::new(text).block(block.clone()), size)
+        });
+
+        // Follow the newest toast, which is pushed in at the bottom.
+        state.list_state.select(Some(count - 1));
+        ListView::new(builder, count)
+            .main_axis_alignment(MainAxisAlignment::End)
+            .infinite_scrolling(false)
+            .render(area, buf, &mut state.list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_and_limits_toasts() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = ToastState::new(Duration::from_secs(2));
+        state.push("a");
+        state.push_with_ttl("b", Duration::from_secs(5));
+        state.push("c");
+        let render = |state: &mut ToastState| {
+            let mut buf = Buffer::empty(area);
+            ToastListView::new()
+                .block(Block::new())
+                .max_visible(2)
+                .render(area, &mut buf, state);
+            buf
+        };
+
+        // when
+        let buf = render(&mut state);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(["   ", "b  ", "c  "]));
+
+        // when
+        state.tick(Duration::from_secs(2));
+        let buf = render(&mut state);
+
+        // then
+        assert_eq!(state.len(), 1);
+        assert_eq!(buf, Buffer::with_lines(["   ", "   ", "b  "]));
+    }
+}